@@ -5,13 +5,19 @@ pub mod derive_attr {
     #[derive(Default, FromAttributes)]
     pub struct SeaOrm {
         pub column: Option<syn::Ident>,
+        pub created_at: Option<syn::Lit>,
+        pub default_scope: Option<syn::Lit>,
         pub entity: Option<syn::Ident>,
         pub model: Option<syn::Ident>,
         pub primary_key: Option<syn::Ident>,
         pub relation: Option<syn::Ident>,
         pub schema_name: Option<syn::Lit>,
+        pub soft_delete: Option<syn::Lit>,
         pub table_name: Option<syn::Lit>,
         pub table_iden: Option<()>,
+        pub tenant_column: Option<syn::Lit>,
+        pub updated_at: Option<syn::Lit>,
+        pub version_column: Option<syn::Lit>,
     }
 }
 
@@ -1,18 +1,25 @@
 use std::iter::FromIterator;
 
-use proc_macro2::TokenStream;
+use heck::CamelCase;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
 
-use crate::attributes::derive_attr;
+use crate::{attributes::derive_attr, util::escape_rust_keyword};
 
 struct DeriveEntity {
     column_ident: syn::Ident,
+    created_at: Option<syn::Ident>,
+    default_scope: Option<syn::Path>,
     ident: syn::Ident,
     model_ident: syn::Ident,
     primary_key_ident: syn::Ident,
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
+    soft_delete: Option<syn::Ident>,
     table_name: Option<syn::Lit>,
+    tenant_column: Option<syn::Ident>,
+    updated_at: Option<syn::Ident>,
+    version_column: Option<syn::Ident>,
 }
 
 impl DeriveEntity {
@@ -31,15 +38,36 @@ impl DeriveEntity {
 
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
+        let soft_delete = column_variant("soft_delete", sea_attr.soft_delete)?;
+        let version_column = column_variant("version_column", sea_attr.version_column)?;
+        let created_at = column_variant("created_at", sea_attr.created_at)?;
+        let updated_at = column_variant("updated_at", sea_attr.updated_at)?;
+        let tenant_column = column_variant("tenant_column", sea_attr.tenant_column)?;
+        let default_scope = match sea_attr.default_scope {
+            Some(syn::Lit::Str(litstr)) => Some(litstr.parse()?),
+            Some(lit) => {
+                return Err(syn::Error::new_spanned(
+                    &lit,
+                    format!("Invalid default_scope {:?}", lit),
+                ))
+            }
+            None => None,
+        };
 
         Ok(DeriveEntity {
             column_ident,
+            created_at,
+            default_scope,
             ident,
             model_ident,
             primary_key_ident,
             relation_ident,
             schema_name,
+            soft_delete,
             table_name,
+            tenant_column,
+            updated_at,
+            version_column,
         })
     }
 
@@ -90,9 +118,77 @@ impl DeriveEntity {
             column_ident,
             primary_key_ident,
             relation_ident,
+            soft_delete,
+            version_column,
+            created_at,
+            updated_at,
+            default_scope,
+            tenant_column,
             ..
         } = self;
 
+        let expanded_soft_delete_column = soft_delete
+            .as_ref()
+            .map(|soft_delete| {
+                quote!(
+                    fn soft_delete_column() -> Option<Self::Column> {
+                        Some(#column_ident::#soft_delete)
+                    }
+                )
+            })
+            .unwrap_or_default();
+        let expanded_version_column = version_column
+            .as_ref()
+            .map(|version_column| {
+                quote!(
+                    fn version_column() -> Option<Self::Column> {
+                        Some(#column_ident::#version_column)
+                    }
+                )
+            })
+            .unwrap_or_default();
+
+        let expanded_created_at_column = created_at
+            .as_ref()
+            .map(|created_at| {
+                quote!(
+                    fn created_at_column() -> Option<Self::Column> {
+                        Some(#column_ident::#created_at)
+                    }
+                )
+            })
+            .unwrap_or_default();
+        let expanded_updated_at_column = updated_at
+            .as_ref()
+            .map(|updated_at| {
+                quote!(
+                    fn updated_at_column() -> Option<Self::Column> {
+                        Some(#column_ident::#updated_at)
+                    }
+                )
+            })
+            .unwrap_or_default();
+        let expanded_default_scope = default_scope
+            .as_ref()
+            .map(|default_scope| {
+                quote!(
+                    fn default_scope() -> Option<sea_orm::sea_query::Condition> {
+                        #default_scope().into()
+                    }
+                )
+            })
+            .unwrap_or_default();
+        let expanded_tenant_column = tenant_column
+            .as_ref()
+            .map(|tenant_column| {
+                quote!(
+                    fn tenant_column() -> Option<Self::Column> {
+                        Some(#column_ident::#tenant_column)
+                    }
+                )
+            })
+            .unwrap_or_default();
+
         quote!(
             #[automatically_derived]
             impl sea_orm::entity::EntityTrait for #ident {
@@ -103,6 +199,18 @@ impl DeriveEntity {
                 type PrimaryKey = #primary_key_ident;
 
                 type Relation = #relation_ident;
+
+                #expanded_soft_delete_column
+
+                #expanded_version_column
+
+                #expanded_created_at_column
+
+                #expanded_updated_at_column
+
+                #expanded_default_scope
+
+                #expanded_tenant_column
             }
         )
     }
@@ -134,6 +242,21 @@ impl DeriveEntity {
     }
 }
 
+/// Turn a column name given as a string literal into the identifier of its Column variant
+fn column_variant(attr: &str, lit: Option<syn::Lit>) -> syn::Result<Option<syn::Ident>> {
+    match lit {
+        Some(syn::Lit::Str(litstr)) => Ok(Some(syn::Ident::new(
+            &escape_rust_keyword(litstr.value().to_camel_case()),
+            Span::call_site(),
+        ))),
+        Some(lit) => Err(syn::Error::new_spanned(
+            &lit,
+            format!("Invalid {} {:?}", attr, lit),
+        )),
+        None => Ok(None),
+    }
+}
+
 pub fn expand_derive_entity(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     Ok(DeriveEntity::new(input)?.expand())
 }
@@ -12,6 +12,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     // if #[sea_orm(table_name = "foo", schema_name = "bar")] specified, create Entity struct
     let mut table_name = None;
     let mut schema_name = quote! { None };
+    let mut entity_attrs: Punctuated<_, Comma> = Punctuated::new();
     let mut table_iden = false;
     attrs.iter().for_each(|attr| {
         if attr.path.get_ident().map(|i| i == "sea_orm") != Some(true) {
@@ -27,6 +28,12 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         } else if ident == "schema_name" {
                             let name = &nv.lit;
                             schema_name = quote! { Some(#name) };
+                        } else if ident == "soft_delete" {
+                            let name = &nv.lit;
+                            entity_attrs.push(quote! { soft_delete = #name });
+                        } else if ident == "default_scope" {
+                            let name = &nv.lit;
+                            entity_attrs.push(quote! { default_scope = #name });
                         }
                     }
                 } else if let Meta::Path(path) = meta {
@@ -39,35 +46,19 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
             }
         }
     });
-    let entity_def = table_name
-        .as_ref()
-        .map(|table_name| {
-            quote! {
-                #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
-                pub struct Entity;
-
-                #[automatically_derived]
-                impl sea_orm::prelude::EntityName for Entity {
-                    fn schema_name(&self) -> Option<&str> {
-                        #schema_name
-                    }
-
-                    fn table_name(&self) -> &str {
-                        #table_name
-                    }
-                }
-            }
-        })
-        .unwrap_or_default();
-
     // generate Column enum and it's ColumnTrait impl
     let mut columns_enum: Punctuated<_, Comma> = Punctuated::new();
     let mut columns_trait: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_keys: Punctuated<_, Comma> = Punctuated::new();
     let mut primary_key_types: Punctuated<_, Comma> = Punctuated::new();
     let mut auto_increment = true;
+    let mut version_column = None;
+    let mut created_at = None;
+    let mut updated_at = None;
+    let mut tenant_column = None;
+    let mut current_timestamps: Punctuated<_, Comma> = Punctuated::new();
     if table_iden {
-        if let Some(table_name) = table_name {
+        if let Some(table_name) = &table_name {
             let table_field_name = Ident::new("Table", Span::call_site());
             columns_enum.push(quote! {
                 #[sea_orm(table_name=#table_name)]
@@ -104,7 +95,11 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     };
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    let mut is_version = false;
+                    let mut is_created_at = false;
+                    let mut is_updated_at = false;
+                    let mut is_tenant = false;
+                    // search for #[sea_orm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique, version, created_at, updated_at, tenant)]
                     for attr in field.attrs.iter() {
                         if let Some(ident) = attr.path.get_ident() {
                             if ident != "sea_orm" {
@@ -186,6 +181,14 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                                 indexed = true;
                                             } else if name == "unique" {
                                                 unique = true;
+                                            } else if name == "version" {
+                                                is_version = true;
+                                            } else if name == "created_at" {
+                                                is_created_at = true;
+                                            } else if name == "updated_at" {
+                                                is_updated_at = true;
+                                            } else if name == "tenant" {
+                                                is_tenant = true;
                                             }
                                         }
                                     }
@@ -217,6 +220,55 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         });
                     }
 
+                    if is_version {
+                        if version_column.is_some() {
+                            return Err(Error::new(
+                                field.span(),
+                                "Only one version column is allowed",
+                            ));
+                        }
+                        version_column = Some(field_name.to_string());
+                    }
+
+                    if is_created_at {
+                        if created_at.is_some() {
+                            return Err(Error::new(
+                                field.span(),
+                                "Only one created_at column is allowed",
+                            ));
+                        }
+                        created_at = Some(field_name.to_string());
+                    }
+
+                    if is_updated_at {
+                        if updated_at.is_some() {
+                            return Err(Error::new(
+                                field.span(),
+                                "Only one updated_at column is allowed",
+                            ));
+                        }
+                        updated_at = Some(field_name.to_string());
+                    }
+
+                    if is_tenant {
+                        if tenant_column.is_some() {
+                            return Err(Error::new(
+                                field.span(),
+                                "Only one tenant column is allowed",
+                            ));
+                        }
+                        tenant_column = Some(field_name.to_string());
+                    }
+
+                    if is_created_at || is_updated_at {
+                        let field_type = &field.ty;
+                        current_timestamps.push(quote! {
+                            Self::#field_name => Some(
+                                <#field_type as sea_orm::CurrentTimestamp>::current_timestamp().into()
+                            )
+                        });
+                    }
+
                     if is_primary_key {
                         primary_keys.push(quote! {
                             #variant_attrs
@@ -307,6 +359,58 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
         }
     }
 
+    if let Some(name) = version_column {
+        entity_attrs.push(quote! { version_column = #name });
+    }
+    if let Some(name) = created_at {
+        entity_attrs.push(quote! { created_at = #name });
+    }
+    if let Some(name) = updated_at {
+        entity_attrs.push(quote! { updated_at = #name });
+    }
+    if let Some(name) = tenant_column {
+        entity_attrs.push(quote! { tenant_column = #name });
+    }
+    let entity_attrs = if entity_attrs.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[sea_orm(#entity_attrs)] }
+    };
+    let entity_def = table_name
+        .as_ref()
+        .map(|table_name| {
+            quote! {
+                #[derive(Copy, Clone, Default, Debug, sea_orm::prelude::DeriveEntity)]
+                #entity_attrs
+                pub struct Entity;
+
+                #[automatically_derived]
+                impl sea_orm::prelude::EntityName for Entity {
+                    fn schema_name(&self) -> Option<&str> {
+                        #schema_name
+                    }
+
+                    fn table_name(&self) -> &str {
+                        #table_name
+                    }
+                }
+            }
+        })
+        .unwrap_or_default();
+
+    let expanded_current_timestamp = if current_timestamps.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn current_timestamp(&self) -> Option<sea_orm::Value> {
+                match self {
+                    #current_timestamps,
+                    _ => None,
+                }
+            }
+        }
+    };
+
     let primary_key = {
         let auto_increment = auto_increment && primary_keys.len() == 1;
         let primary_key_types = if primary_key_types.len() == 1 {
@@ -347,6 +451,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     #columns_trait
                 }
             }
+
+            #expanded_current_timestamp
         }
 
         #entity_def
@@ -1,7 +1,10 @@
 use crate::{
-    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionError,
+    database::retry::{check_backoff, is_retryable, sleep},
+    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Retried, RetryPolicy,
+    SchemaConnection, Statement, TenantConnection, TransactionError,
 };
 use futures::Stream;
+use sea_query::Value;
 use std::{future::Future, pin::Pin};
 
 /// Creates constraints for any structure that can create a database connection
@@ -21,16 +24,157 @@ pub trait ConnectionTrait: Sync {
     /// Execute a [Statement] and return a collection Vec<[QueryResult]> on success
     async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr>;
 
-    /// Check if the connection supports `RETURNING` syntax on insert and update
+    /// Check if the connection supports `RETURNING` syntax on insert and delete
     fn support_returning(&self) -> bool {
         let db_backend = self.get_database_backend();
         db_backend.support_returning()
     }
 
+    /// Check if the connection supports `RETURNING` syntax on update,
+    /// which MariaDB doesn't even though it does on insert and delete
+    fn support_update_returning(&self) -> bool {
+        self.support_returning() && self.get_database_backend() != DbBackend::MySql
+    }
+
     /// Check if the connection is a test connection for the Mock database
     fn is_mock_connection(&self) -> bool {
         false
     }
+
+    /// The tenant bound to the connection by [`ConnectionTrait::with_tenant`], if any
+    fn tenant(&self) -> Option<Value> {
+        None
+    }
+
+    /// Bind a tenant to the connection, so that queries on Entities having a
+    /// [tenant column](crate::EntityTrait::tenant_column) only see the rows of the tenant,
+    /// and inserts fill the column with the tenant unless it is set explicitly
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![Vec::<invoice::Model>::new()])
+    /// #     .append_exec_results(vec![MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 1,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::invoice};
+    ///
+    /// let tenant = db.with_tenant(7);
+    ///
+    /// let invoices = invoice::Entity::find().all(&tenant).await?;
+    /// assert!(invoices.is_empty());
+    ///
+    /// invoice::Entity::insert(invoice::ActiveModel {
+    ///     id: Set(1),
+    ///     total: Set(10),
+    ///     ..Default::default()
+    /// })
+    /// .exec_without_returning(&tenant)
+    /// .await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"SELECT "invoice"."id", "invoice"."tenant_id", "invoice"."total" FROM "invoice" WHERE "invoice"."tenant_id" = $1"#,
+    ///             vec![7i32.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "invoice" ("id", "total", "tenant_id") VALUES ($1, $2, $3)"#,
+    ///             vec![1i32.into(), 10i32.into(), 7i32.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_tenant<T>(&self, tenant: T) -> TenantConnection<'_, Self>
+    where
+        T: Into<Value>,
+        Self: Sized,
+    {
+        TenantConnection::new(self, tenant.into())
+    }
+
+    /// The schema bound to the connection by [`ConnectionTrait::with_schema`], if any
+    fn schema_name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Bind a schema to the connection, so that queries refer to the tables of the Entities
+    /// in that schema instead of the one given by `#[sea_orm(schema_name = "..")]`
+    ///
+    /// See [`SchemaConnection`] for the tables affected.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![Vec::<cake::Model>::new()])
+    /// #     .append_exec_results(vec![MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 1,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, fruit}};
+    ///
+    /// let tenant = db.with_schema("tenant_a");
+    ///
+    /// let cakes = cake::Entity::find()
+    ///     .find_also_related(fruit::Entity)
+    ///     .all(&tenant)
+    ///     .await?;
+    /// assert!(cakes.is_empty());
+    ///
+    /// cake::Entity::delete_by_id(1).exec(&tenant).await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             [
+    ///                 r#"SELECT "cake"."id" AS "A_id", "cake"."name" AS "A_name","#,
+    ///                 r#""fruit"."id" AS "B_id", "fruit"."name" AS "B_name", "fruit"."cake_id" AS "B_cake_id""#,
+    ///                 r#"FROM "tenant_a"."cake" LEFT JOIN "tenant_a"."fruit" ON "cake"."id" = "fruit"."cake_id""#,
+    ///             ]
+    ///             .join(" ")
+    ///             .as_str(),
+    ///             vec![]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"DELETE FROM "tenant_a"."cake" WHERE "cake"."id" = $1"#,
+    ///             vec![1i32.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_schema(&self, schema: &str) -> SchemaConnection<'_, Self>
+    where
+        Self: Sized,
+    {
+        SchemaConnection::new(self, schema.to_owned())
+    }
 }
 
 /// Stream query results
@@ -130,4 +274,88 @@ pub trait TransactionTrait {
             + Send,
         T: Send,
         E: std::error::Error + Send;
+
+    /// Execute the function inside a transaction with isolation level and/or access mode,
+    /// running it again in a new transaction as long as it fails on a
+    /// [retryable error](DbErr::is_transaction_retryable), up to the number of attempts
+    /// of the [RetryPolicy]. The error of the function is retried if it is, or is caused by,
+    /// such a [DbErr]. The result or the last error is returned along with the number of attempts.
+    ///
+    /// Without a `runtime-*` feature the attempts cannot be delayed, so a [RetryPolicy] with
+    /// a backoff fails before the first attempt unless its backoff is set to zero.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![cake::Model {
+    /// #         id: 1,
+    /// #         name: "New York Cheese".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    /// use std::time::Duration;
+    ///
+    /// let cakes = db
+    ///     .transaction_with_retry::<_, _, DbErr>(
+    ///         |txn| Box::pin(async move { cake::Entity::find().all(txn).await }),
+    ///         Some(IsolationLevel::Serializable),
+    ///         None,
+    ///         RetryPolicy::new()
+    ///             .max_attempts(5)
+    ///             .backoff(Duration::ZERO),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(cakes.value.len(), 1);
+    /// assert_eq!(cakes.attempts, 1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn transaction_with_retry<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+        retry: &RetryPolicy,
+    ) -> Result<Retried<T>, Retried<TransactionError<E>>>
+    where
+        Self: Sync,
+        F: for<'c> Fn(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send
+            + Sync,
+        T: Send,
+        E: std::error::Error + Send + 'static,
+    {
+        if let Err(err) = check_backoff(retry) {
+            return Err(Retried {
+                value: TransactionError::Connection(err),
+                attempts: 0,
+            });
+        }
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let res = match self.begin_with_config(isolation_level, access_mode).await {
+                Ok(transaction) => transaction.run(&callback).await,
+                Err(err) => Err(TransactionError::Connection(err)),
+            };
+            match res {
+                Ok(value) => return Ok(Retried { value, attempts }),
+                Err(err) if attempts < retry.max_attempts && is_retryable(&err) => {
+                    sleep(retry.delay(attempts)).await;
+                }
+                Err(value) => return Err(Retried { value, attempts }),
+            }
+        }
+    }
 }
@@ -158,6 +158,22 @@ impl ConnectionTrait for DatabaseConnection {
         }
     }
 
+    fn support_returning(&self) -> bool {
+        match self {
+            #[cfg(feature = "sqlx-mysql")]
+            DatabaseConnection::SqlxMySqlPoolConnection(conn) => conn.support_returning(),
+            #[cfg(feature = "sqlx-postgres")]
+            DatabaseConnection::SqlxPostgresPoolConnection(_) => true,
+            #[cfg(feature = "sqlx-sqlite")]
+            DatabaseConnection::SqlxSqlitePoolConnection(conn) => conn.support_returning(),
+            #[cfg(feature = "mock")]
+            DatabaseConnection::MockDatabaseConnection(conn) => {
+                conn.get_database_backend().support_returning()
+            }
+            DatabaseConnection::Disconnected => panic!("Disconnected"),
+        }
+    }
+
     #[cfg(feature = "mock")]
     fn is_mock_connection(&self) -> bool {
         matches!(self, DatabaseConnection::MockDatabaseConnection(_))
@@ -411,10 +427,21 @@ impl DbBackend {
         }
     }
 
-    /// Check if the database supports `RETURNING` syntax on insert and update
+    /// Check if the database supports `RETURNING` syntax on insert, update and delete
+    /// regardless of the server version.
+    /// Use [`ConnectionTrait::support_returning`] to take the version of the connected server into account.
     pub fn support_returning(&self) -> bool {
         matches!(self, Self::Postgres)
     }
+
+    /// A human readable name of the database backend
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MySql => "MySQL",
+            Self::Postgres => "PostgreSQL",
+            Self::Sqlite => "SQLite",
+        }
+    }
 }
 
 #[cfg(test)]
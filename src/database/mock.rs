@@ -376,8 +376,8 @@ impl OpenTransaction {
 #[cfg(feature = "mock")]
 mod tests {
     use crate::{
-        entity::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, RuntimeErr,
-        Statement, Transaction, TransactionError, TransactionTrait,
+        entity::*, tests_cfg::*, DbBackend, DbErr, IntoMockRow, MockDatabase, MockExecResult,
+        RuntimeErr, Statement, Transaction, TransactionError, TransactionTrait,
     };
     use pretty_assertions::assert_eq;
 
@@ -585,6 +585,129 @@ mod tests {
         );
     }
 
+    type HookLog = std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>;
+
+    fn hook(log: &HookLog, event: &'static str) -> impl FnOnce() + Send + 'static {
+        let log = log.clone();
+        move || log.lock().unwrap().push(event)
+    }
+
+    #[smol_potat::test]
+    async fn test_transaction_hooks() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let log = HookLog::default();
+
+        let txn = db.begin().await?;
+        txn.on_commit(hook(&log, "commit 1"));
+        txn.on_rollback(hook(&log, "rollback 1"));
+        assert!(log.lock().unwrap().is_empty());
+        txn.commit().await?;
+
+        let txn = db.begin().await?;
+        txn.on_commit(hook(&log, "commit 2"));
+        txn.on_rollback(hook(&log, "rollback 2"));
+        txn.rollback().await?;
+
+        let txn = db.begin().await?;
+        txn.on_commit(hook(&log, "commit 3"));
+        txn.on_rollback(hook(&log, "rollback 3"));
+        drop(txn);
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["commit 1", "rollback 2", "rollback 3"]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_nested_transaction_hooks() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+        let log = HookLog::default();
+
+        let txn = db.begin().await?;
+        txn.on_commit(hook(&log, "outer commit"));
+        {
+            let nested = txn.begin().await?;
+            nested.on_commit(hook(&log, "released commit"));
+            nested.on_rollback(hook(&log, "released rollback"));
+            nested.commit().await?;
+        }
+        {
+            let nested = txn.begin().await?;
+            nested.on_commit(hook(&log, "rolled back commit"));
+            nested.on_rollback(hook(&log, "rolled back rollback"));
+            nested.rollback().await?;
+        }
+        assert_eq!(*log.lock().unwrap(), vec!["rolled back rollback"]);
+        txn.commit().await?;
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["rolled back rollback", "outer commit", "released commit"]
+        );
+        log.lock().unwrap().clear();
+
+        let txn = db.begin().await?;
+        txn.on_commit(hook(&log, "outer commit"));
+        {
+            let nested = txn.begin().await?;
+            nested.on_commit(hook(&log, "released commit"));
+            nested.on_rollback(hook(&log, "released rollback"));
+            nested.commit().await?;
+        }
+        drop(txn);
+
+        assert_eq!(*log.lock().unwrap(), vec!["released rollback"]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn test_savepoint_hooks() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                };
+                4
+            ])
+            .into_connection();
+        let log = HookLog::default();
+
+        let txn = db.begin().await?;
+        txn.on_commit(hook(&log, "commit 1"));
+        txn.savepoint("row").await?;
+        txn.on_commit(hook(&log, "commit 2"));
+        txn.on_rollback(hook(&log, "rollback 2"));
+        txn.rollback_to("row").await?;
+        assert_eq!(*log.lock().unwrap(), vec!["rollback 2"]);
+        txn.on_commit(hook(&log, "commit 3"));
+        txn.release("row").await?;
+        txn.savepoint("row").await?;
+        txn.commit().await?;
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["rollback 2", "commit 1", "commit 3"]
+        );
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                Statement::from_string(DbBackend::MySql, "SAVEPOINT `row`".to_owned()),
+                Statement::from_string(DbBackend::MySql, "ROLLBACK TO SAVEPOINT `row`".to_owned()),
+                Statement::from_string(DbBackend::MySql, "RELEASE SAVEPOINT `row`".to_owned()),
+                Statement::from_string(DbBackend::MySql, "SAVEPOINT `row`".to_owned()),
+                Statement::from_string(DbBackend::MySql, "COMMIT".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
     #[smol_potat::test]
     async fn test_stream_1() -> Result<(), DbErr> {
         use futures::TryStreamExt;
@@ -4,17 +4,25 @@ mod connection;
 mod db_connection;
 #[cfg(feature = "mock")]
 mod mock;
+mod replica;
+mod retry;
+mod runtime_schema;
 mod statement;
 mod stream;
+mod tenant;
 mod transaction;
 
 pub use connection::*;
 pub use db_connection::*;
 #[cfg(feature = "mock")]
 pub use mock::*;
+pub use replica::*;
+pub use retry::*;
+pub use runtime_schema::*;
 pub use statement::*;
 use std::borrow::Cow;
 pub use stream::*;
+pub use tenant::*;
 use tracing::instrument;
 pub use transaction::*;
 
@@ -50,6 +58,8 @@ pub struct ConnectOptions {
     pub(crate) sqlcipher_key: Option<Cow<'static, str>>,
     /// Schema search path (PostgreSQL only)
     pub(crate) schema_search_path: Option<String>,
+    /// The URIs of the read replicas of the database
+    pub(crate) replica_urls: Vec<String>,
 }
 
 impl Database {
@@ -82,6 +92,24 @@ impl Database {
             opt.url
         ))))
     }
+
+    /// Method to create a [ReplicatedConnection] on a database and its read replicas,
+    /// given by [ConnectOptions::replica_url]. Each replica is connected with the
+    /// same options as the primary.
+    #[instrument(level = "trace", skip(opt))]
+    pub async fn connect_replicated<C>(opt: C) -> Result<ReplicatedConnection, DbErr>
+    where
+        C: Into<ConnectOptions>,
+    {
+        let mut opt: ConnectOptions = opt.into();
+        let replica_urls = std::mem::take(&mut opt.replica_urls);
+        let mut replicas = Vec::with_capacity(replica_urls.len());
+        for url in replica_urls {
+            replicas.push(Self::connect(ConnectOptions { url, ..opt.clone() }).await?);
+        }
+        let primary = Self::connect(opt).await?;
+        Ok(ReplicatedConnection::new(primary, replicas))
+    }
 }
 
 impl From<&str> for ConnectOptions {
@@ -117,6 +145,7 @@ impl ConnectOptions {
             sqlx_logging_level: log::LevelFilter::Info,
             sqlcipher_key: None,
             schema_search_path: None,
+            replica_urls: Vec::new(),
         }
     }
 
@@ -260,4 +289,18 @@ impl ConnectOptions {
         self.schema_search_path = Some(schema_search_path);
         self
     }
+
+    /// Add the URI of a read replica, for [Database::connect_replicated]
+    pub fn replica_url<T>(&mut self, url: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.replica_urls.push(url.into());
+        self
+    }
+
+    /// Get the URIs of the read replicas
+    pub fn get_replica_urls(&self) -> &[String] {
+        &self.replica_urls
+    }
 }
@@ -0,0 +1,370 @@
+use crate::{
+    error::*, AccessMode, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend,
+    ExecResult, IsolationLevel, QueryResult, Statement, StreamTrait, TransactionError,
+    TransactionTrait,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tracing::instrument;
+
+/// A primary database along with its read replicas, as returned by
+/// [`Database::connect_replicated`](crate::Database::connect_replicated)
+///
+/// `SELECT` statements executed outside of a transaction are sent to the replicas
+/// in turn, while every other statement and all transactions go to the primary.
+/// As replicas may lag behind the primary, use [`ReplicatedConnection::pin_to_primary`]
+/// to read the rows just written.
+#[derive(Debug, Clone)]
+pub struct ReplicatedConnection {
+    inner: Arc<Replicas>,
+    pinned: bool,
+}
+
+#[derive(Debug)]
+struct Replicas {
+    primary: DatabaseConnection,
+    replicas: Vec<DatabaseConnection>,
+    next: AtomicUsize,
+}
+
+impl ReplicatedConnection {
+    /// Create a [ReplicatedConnection] from connections to the primary and to the replicas
+    pub fn new(primary: DatabaseConnection, replicas: Vec<DatabaseConnection>) -> Self {
+        Self {
+            inner: Arc::new(Replicas {
+                primary,
+                replicas,
+                next: AtomicUsize::new(0),
+            }),
+            pinned: false,
+        }
+    }
+
+    /// The connection to the primary
+    pub fn primary(&self) -> &DatabaseConnection {
+        &self.inner.primary
+    }
+
+    /// The connections to the replicas
+    pub fn replicas(&self) -> &[DatabaseConnection] {
+        &self.inner.replicas
+    }
+
+    /// A handle sharing the connections of this one, sending every statement to the primary
+    /// so that it reads its own writes
+    pub fn pin_to_primary(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            pinned: true,
+        }
+    }
+
+    /// Check if the statements are all sent to the primary
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Explicitly close the connections to the primary and to the replicas.
+    /// The connections are closed when the last handle is dropped otherwise.
+    pub async fn close(self) -> Result<(), DbErr> {
+        let inner = Arc::try_unwrap(self.inner).map_err(|_| {
+            DbErr::Conn(RuntimeErr::Internal(
+                "The connection is still in use".to_owned(),
+            ))
+        })?;
+        inner.primary.close().await?;
+        for replica in inner.replicas {
+            replica.close().await?;
+        }
+        Ok(())
+    }
+
+    /// The connection a statement is sent to
+    fn route(&self, stmt: &Statement) -> &DatabaseConnection {
+        let replicas = &self.inner.replicas;
+        if self.pinned || replicas.is_empty() || !is_select(stmt) {
+            return &self.inner.primary;
+        }
+        let next = self.inner.next.fetch_add(1, Ordering::Relaxed);
+        &replicas[next % replicas.len()]
+    }
+}
+
+/// Statements querying rows may also write them, as in `INSERT .. RETURNING`,
+/// or lock them, as in `SELECT .. FOR UPDATE`
+fn is_select(stmt: &Statement) -> bool {
+    stmt.sql
+        .trim_start()
+        .get(..6)
+        .map_or(false, |keyword| keyword.eq_ignore_ascii_case("SELECT"))
+        && !is_locking(&stmt.sql)
+}
+
+/// Locking reads, i.e. `FOR UPDATE`, `FOR NO KEY UPDATE`, `FOR SHARE`, `FOR KEY SHARE`
+/// and `LOCK IN SHARE MODE`, can only lock the rows on the primary
+fn is_locking(sql: &str) -> bool {
+    let words: Vec<_> = sql.split_whitespace().collect();
+    words.windows(2).any(|pair| {
+        let [first, second] = [pair[0], pair[1]].map(str::to_ascii_uppercase);
+        matches!(
+            (first.as_str(), second.as_str()),
+            ("FOR", "UPDATE" | "NO" | "SHARE" | "KEY") | ("LOCK", "IN")
+        )
+    })
+}
+
+#[async_trait::async_trait]
+impl ConnectionTrait for ReplicatedConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.inner.primary.get_database_backend()
+    }
+
+    #[instrument(level = "trace")]
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.inner.primary.execute(stmt).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.route(&stmt).query_one(stmt).await
+    }
+
+    #[instrument(level = "trace")]
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.route(&stmt).query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.inner.primary.support_returning()
+    }
+
+    fn support_update_returning(&self) -> bool {
+        self.inner.primary.support_update_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.inner.primary.is_mock_connection()
+    }
+}
+
+impl StreamTrait for ReplicatedConnection {
+    type Stream<'a> = crate::QueryStream;
+
+    #[instrument(level = "trace")]
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.route(&stmt).stream(stmt)
+    }
+}
+
+#[async_trait::async_trait]
+impl TransactionTrait for ReplicatedConnection {
+    #[instrument(level = "trace")]
+    async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.inner.primary.begin().await
+    }
+
+    #[instrument(level = "trace")]
+    async fn begin_with_config(
+        &self,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<DatabaseTransaction, DbErr> {
+        self.inner
+            .primary
+            .begin_with_config(isolation_level, access_mode)
+            .await
+    }
+
+    /// Execute the function inside a transaction on the primary.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.inner.primary.transaction(callback).await
+    }
+
+    /// Execute the function inside a transaction on the primary.
+    /// If the function returns an error, the transaction will be rolled back. If it does not return an error, the transaction will be committed.
+    #[instrument(level = "trace", skip(callback))]
+    async fn transaction_with_config<F, T, E>(
+        &self,
+        callback: F,
+        isolation_level: Option<IsolationLevel>,
+        access_mode: Option<AccessMode>,
+    ) -> Result<T, TransactionError<E>>
+    where
+        F: for<'c> FnOnce(
+                &'c DatabaseTransaction,
+            ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'c>>
+            + Send,
+        T: Send,
+        E: std::error::Error + Send,
+    {
+        self.inner
+            .primary
+            .transaction_with_config(callback, isolation_level, access_mode)
+            .await
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+
+    fn transaction_log(conn: &DatabaseConnection) -> Vec<Transaction> {
+        conn.as_mock_connection()
+            .get_mocker_mutex()
+            .lock()
+            .unwrap()
+            .drain_transaction_log()
+    }
+
+    fn select_cake() -> Statement {
+        Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+            vec![],
+        )
+    }
+
+    fn replica() -> DatabaseConnection {
+        MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new(), Vec::new()])
+            .into_connection()
+    }
+
+    #[smol_potat::test]
+    async fn replica_round_robin() -> Result<(), DbErr> {
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "Apple Pie".to_owned(),
+            }]])
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let db = ReplicatedConnection::new(primary, vec![replica(), replica()]);
+
+        for _ in 0..3 {
+            cake::Entity::find().all(&db).await?;
+        }
+        cake::Entity::insert(cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        })
+        .exec_with_returning(&db)
+        .await?;
+        cake::Entity::delete_by_id(1).exec(&db).await?;
+
+        assert_eq!(
+            transaction_log(db.primary()),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "cake" ("name") VALUES ($1) RETURNING "id", "name""#,
+                    vec!["Apple Pie".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "cake" WHERE "cake"."id" = $1"#,
+                    vec![1i32.into()]
+                ),
+            ]
+        );
+        assert_eq!(
+            transaction_log(&db.replicas()[0]),
+            Transaction::wrap([select_cake(), select_cake()])
+        );
+        assert_eq!(
+            transaction_log(&db.replicas()[1]),
+            Transaction::wrap([select_cake()])
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn replica_pin_to_primary() -> Result<(), DbErr> {
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new(), Vec::new()])
+            .into_connection();
+        let db = ReplicatedConnection::new(primary, vec![replica()]);
+        let pinned = db.pin_to_primary();
+
+        cake::Entity::find().all(&pinned).await?;
+        let txn = db.begin().await?;
+        cake::Entity::find().all(&txn).await?;
+        txn.commit().await?;
+
+        assert!(pinned.is_pinned());
+        assert!(!db.is_pinned());
+        assert_eq!(
+            transaction_log(db.primary()),
+            vec![
+                Transaction::one(select_cake()),
+                Transaction::many(vec![
+                    Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                    select_cake(),
+                    Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+                ]),
+            ]
+        );
+        assert_eq!(transaction_log(&db.replicas()[0]), vec![]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn replica_locking_select() -> Result<(), DbErr> {
+        let primary = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![Vec::<cake::Model>::new(), Vec::new()])
+            .into_connection();
+        let db = ReplicatedConnection::new(primary, vec![replica()]);
+
+        cake::Entity::find().lock_exclusive().all(&db).await?;
+        cake::Entity::find().lock_shared().all(&db).await?;
+        cake::Entity::find().all(&db).await?;
+
+        assert_eq!(
+            transaction_log(db.primary()),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR UPDATE"#,
+                    vec![]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake" FOR SHARE"#,
+                    vec![]
+                ),
+            ]
+        );
+        assert_eq!(
+            transaction_log(&db.replicas()[0]),
+            Transaction::wrap([select_cake()])
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{DbErr, TransactionError};
+use std::time::Duration;
+
+/// Defines how [`TransactionTrait::transaction_with_retry`](crate::TransactionTrait::transaction_with_retry)
+/// runs a transaction again after a [retryable error](DbErr::is_transaction_retryable)
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of times the transaction is run
+    pub(crate) max_attempts: u32,
+    /// The delay before the second attempt, doubled before each of the next ones
+    pub(crate) backoff: Duration,
+    /// Maximum delay between two attempts
+    pub(crate) max_backoff: Duration,
+}
+
+/// The outcome of a transaction along with the number of times it has been run
+#[derive(Debug)]
+pub struct Retried<T> {
+    /// The result or the error of the last attempt
+    pub value: T,
+    /// The number of times the transaction has been run
+    pub attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a [RetryPolicy] running a transaction up to 3 times,
+    /// waiting 10 milliseconds before the second attempt
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of times the transaction is run
+    pub fn max_attempts(&mut self, value: u32) -> &mut Self {
+        self.max_attempts = value;
+        self
+    }
+
+    /// Get the maximum number of times the transaction is run
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Set the delay before the second attempt, doubled before each of the next ones.
+    /// Delaying the attempts requires a `runtime-*` feature; without one, the backoff has to be
+    /// set to zero to run the attempts right after one another.
+    pub fn backoff(&mut self, value: Duration) -> &mut Self {
+        self.backoff = value;
+        self
+    }
+
+    /// Get the delay before the second attempt
+    pub fn get_backoff(&self) -> Duration {
+        self.backoff
+    }
+
+    /// Set the maximum delay between two attempts
+    pub fn max_backoff(&mut self, value: Duration) -> &mut Self {
+        self.max_backoff = value;
+        self
+    }
+
+    /// Get the maximum delay between two attempts
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// The delay after the given number of failed attempts
+    pub(crate) fn delay(&self, attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempts.saturating_sub(1));
+        self.backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+}
+
+impl<T> std::fmt::Display for Retried<T>
+where
+    T: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (after {} attempts)", self.value, self.attempts)
+    }
+}
+
+impl<T> std::error::Error for Retried<T> where T: std::error::Error {}
+
+/// Check if the transaction failed on an error worth running it again,
+/// looking for a [DbErr] among the sources of an error of the callback
+pub(crate) fn is_retryable<E>(err: &TransactionError<E>) -> bool
+where
+    E: std::error::Error + 'static,
+{
+    let mut source: Option<&(dyn std::error::Error + 'static)> = match err {
+        TransactionError::Connection(err) => Some(err),
+        TransactionError::Transaction(err) => Some(err),
+    };
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<DbErr>() {
+            return err.is_transaction_retryable();
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Check that the attempts can be delayed as the policy requires, which needs a runtime
+#[allow(unused_variables)]
+pub(crate) fn check_backoff(retry: &RetryPolicy) -> Result<(), DbErr> {
+    #[cfg(not(any(
+        feature = "runtime-async-std",
+        feature = "runtime-tokio",
+        feature = "runtime-actix"
+    )))]
+    if retry.max_attempts > 1 && !retry.backoff.is_zero() {
+        return Err(DbErr::Custom(
+            "Delaying the retries requires a runtime-* feature, set a zero backoff to retry immediately"
+                .to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+/// Wait before the next attempt, for a duration checked by [check_backoff]
+#[allow(unused_variables)]
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(any(feature = "runtime-tokio", feature = "runtime-actix"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(all(
+        feature = "runtime-async-std",
+        not(any(feature = "runtime-tokio", feature = "runtime-actix"))
+    ))]
+    async_io::Timer::after(duration).await;
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn retry_policy_delay() {
+        let retry = RetryPolicy::new()
+            .backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .to_owned();
+
+        assert_eq!(retry.delay(1), Duration::from_millis(100));
+        assert_eq!(retry.delay(2), Duration::from_millis(200));
+        assert_eq!(retry.delay(3), Duration::from_millis(300));
+        assert_eq!(retry.delay(40), Duration::from_millis(300));
+    }
+
+    #[smol_potat::test]
+    async fn retry_not_retryable() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_errors(vec![DbErr::Custom("Not retryable".to_owned())])
+            .into_connection();
+
+        let res = db
+            .transaction_with_retry::<_, _, DbErr>(
+                |txn| {
+                    Box::pin(async move {
+                        cake::Entity::find().all(txn).await?;
+                        Ok(())
+                    })
+                },
+                None,
+                None,
+                RetryPolicy::new().backoff(Duration::ZERO),
+            )
+            .await;
+
+        match res {
+            Err(Retried {
+                value: TransactionError::Transaction(err),
+                attempts,
+            }) => {
+                assert_eq!(err, DbErr::Custom("Not retryable".to_owned()));
+                assert_eq!(attempts, 1);
+            }
+            _ => panic!("Should fail on the first attempt"),
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "sqlx-dep")]
+    #[smol_potat::test]
+    async fn retry_serialization_failure() -> Result<(), DbErr> {
+        #[derive(Debug)]
+        struct SerializationFailure;
+
+        impl std::fmt::Display for SerializationFailure {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "could not serialize access")
+            }
+        }
+
+        impl std::error::Error for SerializationFailure {}
+
+        impl sqlx::error::DatabaseError for SerializationFailure {
+            fn message(&self) -> &str {
+                "could not serialize access"
+            }
+
+            fn code(&self) -> Option<std::borrow::Cow<'_, str>> {
+                Some("40001".into())
+            }
+
+            fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+                self
+            }
+
+            fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+                self
+            }
+
+            fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+                self
+            }
+        }
+
+        let serialization_failure = || {
+            DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(Box::new(
+                SerializationFailure,
+            ))))
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_errors(vec![serialization_failure(), serialization_failure()])
+            .append_query_results(vec![Vec::<cake::Model>::new()])
+            .into_connection();
+
+        let res = db
+            .transaction_with_retry::<_, _, DbErr>(
+                |txn| Box::pin(async move { cake::Entity::find().all(txn).await }),
+                Some(IsolationLevel::Serializable),
+                None,
+                RetryPolicy::new().backoff(Duration::ZERO),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.value, vec![]);
+        assert_eq!(res.attempts, 3);
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_errors(vec![serialization_failure(), serialization_failure()])
+            .into_connection();
+
+        let res = db
+            .transaction_with_retry::<_, _, DbErr>(
+                |txn| Box::pin(async move { cake::Entity::find().all(txn).await }),
+                Some(IsolationLevel::Serializable),
+                None,
+                RetryPolicy::new().max_attempts(2).backoff(Duration::ZERO),
+            )
+            .await;
+
+        match res {
+            Err(Retried {
+                value: TransactionError::Transaction(err),
+                attempts,
+            }) => {
+                assert!(err.is_transaction_retryable());
+                assert_eq!(attempts, 2);
+            }
+            _ => panic!("Should fail after the last attempt"),
+        }
+
+        Ok(())
+    }
+    #[cfg(not(any(
+        feature = "runtime-async-std",
+        feature = "runtime-tokio",
+        feature = "runtime-actix"
+    )))]
+    #[smol_potat::test]
+    async fn retry_backoff_without_runtime() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres).into_connection();
+
+        let res = db
+            .transaction_with_retry::<_, _, DbErr>(
+                |txn| Box::pin(async move { cake::Entity::find().all(txn).await }),
+                None,
+                None,
+                &RetryPolicy::new(),
+            )
+            .await;
+
+        match res {
+            Err(Retried {
+                value: TransactionError::Connection(err),
+                attempts,
+            }) => {
+                assert_eq!(
+                    err,
+                    DbErr::Custom(
+                        "Delaying the retries requires a runtime-* feature, set a zero backoff to retry immediately"
+                            .to_owned()
+                    )
+                );
+                assert_eq!(attempts, 0);
+            }
+            _ => panic!("Should fail before the first attempt"),
+        }
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
+}
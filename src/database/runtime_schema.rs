@@ -0,0 +1,595 @@
+use crate::{
+    unpack_table_ref, ConnectionTrait, DbBackend, DbErr, EntityTrait, ExecResult, QueryResult,
+    Statement, StreamTrait, TenantJoin,
+};
+use sea_query::{
+    Alias, BinOper, CommonTableExpression, Condition, ConditionHolder, ConditionHolderContents,
+    EscapeBuilder, Function, Iden, IntoIden, IntoTableRef, JoinExpr, JoinOn, LockClause,
+    LogicalChainOper, OrderExpr, QueryBuilder, QueryStatementBuilder, QuotedBuilder, SeaRc,
+    SelectDistinct, SelectStatement, SimpleExpr, SqlWriter, SubQueryOper, SubQueryStatement,
+    TableRef, TableRefBuilder, Value, WithClause,
+};
+use std::{future::Future, pin::Pin};
+
+/// A connection or transaction bound to a schema, as returned by [`ConnectionTrait::with_schema`]
+///
+/// The inserts, updates and deletes issued through it refer to the table of the Entity in
+/// that schema, instead of the one given by `#[sea_orm(schema_name = "..")]`. So do the selects
+/// for the table of the Entity and, in `find_also_related` and `find_with_related`, of the
+/// related Entity. Tables joined otherwise and raw statements are left as they are;
+/// use [`SchemaConnection::set_search_path`] to resolve every unqualified table in the schema.
+#[derive(Debug, Clone)]
+pub struct SchemaConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    conn: &'c C,
+    schema: String,
+}
+
+impl<'c, C> SchemaConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    pub(crate) fn new(conn: &'c C, schema: String) -> Self {
+        Self { conn, schema }
+    }
+
+    /// The connection the schema is bound to
+    pub fn inner(&self) -> &'c C {
+        self.conn
+    }
+
+    /// Set the Postgres `search_path` to the schema, so that every unqualified table,
+    /// including those of raw statements, is looked up in the schema first
+    ///
+    /// The setting is local to the current transaction, so bind the schema to a
+    /// [`DatabaseTransaction`](crate::DatabaseTransaction) and call this before issuing queries.
+    ///
+    /// Returns [`DbErr::BackendNotSupported`] on other databases.
+    pub async fn set_search_path(&self) -> Result<(), DbErr> {
+        let db_backend = self.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "Search path",
+            });
+        }
+        let mut schema = String::new();
+        Alias::new(&self.schema).prepare(&mut schema, '"');
+        self.conn
+            .execute(Statement::from_string(
+                db_backend,
+                format!("SET LOCAL search_path TO {}", schema),
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<'c, C> ConnectionTrait for SchemaConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute(stmt).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    fn support_update_returning(&self) -> bool {
+        self.conn.support_update_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+
+    fn tenant(&self) -> Option<Value> {
+        self.conn.tenant()
+    }
+
+    fn schema_name(&self) -> Option<&str> {
+        Some(&self.schema)
+    }
+}
+
+impl<'c, C> StreamTrait for SchemaConnection<'c, C>
+where
+    C: ConnectionTrait + StreamTrait,
+{
+    type Stream<'a>
+        = C::Stream<'a>
+    where
+        Self: 'a;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.conn.stream(stmt)
+    }
+}
+
+/// Moves a table into the schema, replacing the schema it is qualified with if any
+pub(crate) fn qualify_table_ref(table_ref: &TableRef, schema: &str) -> TableRef {
+    (Alias::new(schema).into_iden(), unpack_table_ref(table_ref)).into_table_ref()
+}
+
+/// The table of the Entity, in the schema bound to the connection if any
+pub(crate) fn entity_table_ref<E, C>(db: &C) -> TableRef
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let table_ref = E::default().table_ref();
+    match db.schema_name() {
+        Some(schema) => qualify_table_ref(&table_ref, schema),
+        None => table_ref,
+    }
+}
+
+/// The tables of the Entities referred to by a SELECT, moved into the schema bound to the
+/// connection when the query is built, for Entities known only when the query is built
+pub(crate) type SchemaQualifier = fn() -> Vec<TableRef>;
+
+/// The table of the Entity, moved into the schema when a SELECT is built
+pub(crate) fn qualify_schema<E>() -> Vec<TableRef>
+where
+    E: EntityTrait,
+{
+    vec![E::default().table_ref()]
+}
+
+/// The tables of both Entities, moved into the schema when a SELECT is built
+pub(crate) fn qualify_schema_two<E, F>() -> Vec<TableRef>
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    vec![E::default().table_ref(), F::default().table_ref()]
+}
+
+/// A [SchemaQualifier] for queries whose tables are not moved into a schema
+pub(crate) fn no_schema_qualifier() -> Vec<TableRef> {
+    Vec::new()
+}
+
+/// Build a SELECT for the connection, moving the tables into the schema bound to it if any
+/// and restricting the joined Entities to the tenant bound to it if any
+pub(crate) fn build_select<S, C>(
+    statement: &S,
+    qualifier: SchemaQualifier,
+    tenant_join: TenantJoin,
+    db: &C,
+) -> Statement
+where
+    S: QueryStatementBuilder,
+    C: ConnectionTrait,
+{
+    let db_backend = db.get_database_backend();
+    let query_builder = db_backend.get_query_builder();
+    let schema = db.schema_name();
+    let joins = db.tenant().map(tenant_join).unwrap_or_default();
+    let stmt = if schema.is_some() || !joins.is_empty() {
+        statement.build_any(&ConnectionQueryBuilder {
+            inner: query_builder.as_ref(),
+            schema,
+            tables: qualifier(),
+            joins,
+        })
+    } else {
+        statement.build_any(query_builder.as_ref())
+    };
+    Statement::from_string_values_tuple(db_backend, stmt)
+}
+
+/// Build a SELECT for the connection, preceded by the `WITH` clause if any
+pub(crate) fn build_select_with<C>(
+    with: &Option<WithClause>,
+    query: &SelectStatement,
+    qualifier: SchemaQualifier,
+    tenant_join: TenantJoin,
+    db: &C,
+) -> Statement
+where
+    C: ConnectionTrait,
+{
+    match with {
+        Some(with) => build_select(
+            &with.clone().query(query.clone()),
+            qualifier,
+            tenant_join,
+            db,
+        ),
+        None => build_select(query, qualifier, tenant_join, db),
+    }
+}
+
+/// The schema and the name of a table
+fn table_name(table_ref: &TableRef) -> Option<(Option<String>, String)> {
+    match table_ref {
+        TableRef::Table(table) | TableRef::TableAlias(table, _) => Some((None, table.to_string())),
+        TableRef::SchemaTable(schema, table) | TableRef::SchemaTableAlias(schema, table, _) => {
+            Some((Some(schema.to_string()), table.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Builds a query of a connection bound to a schema or a tenant.
+///
+/// The tables of the Entities are moved into the schema wherever the query reads from or joins
+/// them, including in derived tables, unions and common table expressions. The subqueries within
+/// expressions are built as they are. The tenant conditions of the joined tables are added to the
+/// `ON` clause of their joins, so that the rows of other tenants are not joined.
+struct ConnectionQueryBuilder<'a> {
+    inner: &'a dyn QueryBuilder,
+    schema: Option<&'a str>,
+    tables: Vec<TableRef>,
+    joins: Vec<(TableRef, SimpleExpr)>,
+}
+
+impl ConnectionQueryBuilder<'_> {
+    fn qualify(&self, table_ref: &TableRef) -> Option<TableRef> {
+        let schema = self.schema?;
+        let name = table_name(table_ref)?;
+        if !self
+            .tables
+            .iter()
+            .any(|table| table_name(table).as_ref() == Some(&name))
+        {
+            return None;
+        }
+        let schema = Alias::new(schema).into_iden();
+        match table_ref {
+            TableRef::Table(table) | TableRef::SchemaTable(_, table) => {
+                Some(TableRef::SchemaTable(schema, SeaRc::clone(table)))
+            }
+            TableRef::TableAlias(table, alias) | TableRef::SchemaTableAlias(_, table, alias) => {
+                Some(TableRef::SchemaTableAlias(
+                    schema,
+                    SeaRc::clone(table),
+                    SeaRc::clone(alias),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Add the tenant conditions of the joined table to the `ON` clause of the join
+    fn restrict_join(&self, join_expr: &JoinExpr) -> JoinExpr {
+        let mut join_expr = join_expr.clone();
+        let table = match join_expr.table.as_ref() {
+            TableRef::Table(_) | TableRef::SchemaTable(_, _) => table_name(&join_expr.table),
+            _ => return join_expr,
+        };
+        for (join_table, condition) in &self.joins {
+            if table_name(join_table) != table {
+                continue;
+            }
+            let mut on = match join_expr.on.take() {
+                Some(JoinOn::Condition(on)) => *on,
+                _ => ConditionHolder::new(),
+            };
+            match on.contents {
+                ConditionHolderContents::Chain(_) => {
+                    on.add_and_or(LogicalChainOper::And(condition.clone()))
+                }
+                _ => on.add_condition(Condition::all().add(condition.clone())),
+            }
+            join_expr.on = Some(JoinOn::Condition(Box::new(on)));
+        }
+        join_expr
+    }
+}
+
+impl QueryBuilder for ConnectionQueryBuilder<'_> {
+    fn placeholder(&self) -> (&str, bool) {
+        self.inner.placeholder()
+    }
+
+    fn values_list_tuple_prefix(&self) -> &str {
+        self.inner.values_list_tuple_prefix()
+    }
+
+    fn prepare_simple_expr(&self, simple_expr: &SimpleExpr, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_simple_expr(simple_expr, sql)
+    }
+
+    fn prepare_select_distinct(&self, select_distinct: &SelectDistinct, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_select_distinct(select_distinct, sql)
+    }
+
+    fn prepare_select_lock(&self, lock: &LockClause, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_select_lock(lock, sql)
+    }
+
+    fn prepare_table_ref(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
+        match table_ref {
+            TableRef::SubQuery(query, alias) => {
+                write!(sql, "(").unwrap();
+                self.prepare_select_statement(query, sql);
+                write!(sql, ") AS ").unwrap();
+                alias.prepare(sql.as_writer(), self.quote());
+            }
+            _ => match self.qualify(table_ref) {
+                Some(qualified) => self.inner.prepare_table_ref(&qualified, sql),
+                None => self.inner.prepare_table_ref(table_ref, sql),
+            },
+        }
+    }
+
+    fn prepare_join_expr(&self, join_expr: &JoinExpr, sql: &mut dyn SqlWriter) {
+        let join_expr = self.restrict_join(join_expr);
+        self.prepare_join_type(&join_expr.join, sql);
+        write!(sql, " ").unwrap();
+        self.prepare_join_table_ref(&join_expr, sql);
+        if let Some(on) = &join_expr.on {
+            self.prepare_join_on(on, sql);
+        }
+    }
+
+    fn prepare_bin_oper(&self, bin_oper: &BinOper, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_bin_oper(bin_oper, sql)
+    }
+
+    fn prepare_sub_query_oper(&self, oper: &SubQueryOper, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_sub_query_oper(oper, sql)
+    }
+
+    fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut dyn SqlWriter) {
+        match query {
+            SubQueryStatement::SelectStatement(stmt) => self.prepare_select_statement(stmt, sql),
+            SubQueryStatement::InsertStatement(stmt) => self.prepare_insert_statement(stmt, sql),
+            SubQueryStatement::UpdateStatement(stmt) => self.prepare_update_statement(stmt, sql),
+            SubQueryStatement::DeleteStatement(stmt) => self.prepare_delete_statement(stmt, sql),
+            SubQueryStatement::WithStatement(stmt) => self.prepare_with_query(stmt, sql),
+        }
+    }
+
+    fn prepare_with_clause_recursive_options(
+        &self,
+        with_clause: &WithClause,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.inner
+            .prepare_with_clause_recursive_options(with_clause, sql)
+    }
+
+    fn prepare_with_query_clause_materialization(
+        &self,
+        cte: &CommonTableExpression,
+        sql: &mut dyn SqlWriter,
+    ) {
+        self.inner
+            .prepare_with_query_clause_materialization(cte, sql)
+    }
+
+    fn prepare_function(&self, function: &Function, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_function(function, sql)
+    }
+
+    fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_order_expr(order_expr, sql)
+    }
+
+    fn prepare_value(&self, value: &Value, sql: &mut dyn SqlWriter) {
+        self.inner.prepare_value(value, sql)
+    }
+
+    fn write_string_quoted(&self, string: &str, buffer: &mut String) {
+        self.inner.write_string_quoted(string, buffer)
+    }
+
+    fn if_null_function(&self) -> &str {
+        self.inner.if_null_function()
+    }
+
+    fn char_length_function(&self) -> &str {
+        self.inner.char_length_function()
+    }
+
+    fn random_function(&self) -> &str {
+        self.inner.random_function()
+    }
+}
+
+impl QuotedBuilder for ConnectionQueryBuilder<'_> {
+    fn quote(&self) -> char {
+        self.inner.quote()
+    }
+}
+
+impl EscapeBuilder for ConnectionQueryBuilder<'_> {
+    fn escape_string(&self, string: &str) -> String {
+        self.inner.escape_string(string)
+    }
+
+    fn unescape_string(&self, string: &str) -> String {
+        self.inner.unescape_string(string)
+    }
+}
+
+impl TableRefBuilder for ConnectionQueryBuilder<'_> {}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+    use sea_query::Expr;
+
+    fn exec_result() -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 1,
+            rows_affected: 1,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn schema_select() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_query_results(vec![
+                vec![maplit::btreemap! {
+                    "num_items" => Into::<Value>::into(1i32),
+                }],
+                vec![],
+                vec![],
+            ])
+            .into_connection();
+        let tenant = db.with_schema("tenant_a");
+
+        cake_filling_price::Entity::find().count(&tenant).await?;
+        cake::Entity::find()
+            .filter(
+                cake::Column::Id.in_subquery(
+                    sea_query::Query::select()
+                        .column(fruit::Column::CakeId)
+                        .from(fruit::Entity)
+                        .to_owned(),
+                ),
+            )
+            .all(&tenant)
+            .await?;
+        cake::Entity::find()
+            .find_also_related(fruit::Entity)
+            .filter(Expr::cust("`cake`.`name` <> ' JOIN `fruit`'"))
+            .all(&tenant)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"SELECT COUNT(*) AS num_items FROM (SELECT `cake_filling_price`.`cake_id`,"#,
+                        r#"`cake_filling_price`.`filling_id`, `cake_filling_price`.`price`"#,
+                        r#"FROM `tenant_a`.`cake_filling_price`) AS `sub_query`"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"SELECT `cake`.`id`, `cake`.`name` FROM `tenant_a`.`cake`"#,
+                        r#"WHERE `cake`.`id` IN (SELECT `cake_id` FROM `fruit`)"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    [
+                        r#"SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,"#,
+                        r#"`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`, `fruit`.`cake_id` AS `B_cake_id`"#,
+                        r#"FROM `tenant_a`.`cake` LEFT JOIN `tenant_a`.`fruit` ON `cake`.`id` = `fruit`.`cake_id`"#,
+                        r#"WHERE `cake`.`name` <> ' JOIN `fruit`'"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn schema_insert_update_delete() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![exec_result(), exec_result(), exec_result()])
+            .into_connection();
+        let tenant = db.with_schema("tenant_a");
+
+        cake::Entity::insert(cake::ActiveModel {
+            name: Set("Apple Pie".to_owned()),
+            ..Default::default()
+        })
+        .exec_without_returning(&tenant)
+        .await?;
+        cake::Entity::update_many()
+            .col_expr(cake::Column::Name, Expr::value("Orange Scone"))
+            .filter(cake::Column::Id.eq(1))
+            .exec(&tenant)
+            .await?;
+        cake::Entity::update_batch([cake::ActiveModel {
+            id: Unchanged(1),
+            name: Set("Cheese Cake".to_owned()),
+        }])
+        .exec(&tenant)
+        .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"INSERT INTO "tenant_a"."cake" ("name") VALUES (?)"#,
+                    vec!["Apple Pie".into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"UPDATE "tenant_a"."cake" SET "name" = ? WHERE "cake"."id" = ?"#,
+                    vec!["Orange Scone".into(), 1i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"UPDATE "tenant_a"."cake" SET "name" = (CASE WHEN ("cake"."id" = ?) THEN ? ELSE "cake"."name" END) WHERE "cake"."id" IN (?)"#,
+                    vec![1i32.into(), "Cheese Cake".into(), 1i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn schema_search_path() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![exec_result()])
+            .into_connection();
+
+        db.with_schema("tenant_a").set_search_path().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::one(Statement::from_string(
+                DbBackend::Postgres,
+                r#"SET LOCAL search_path TO "tenant_a""#.to_owned(),
+            ))]
+        );
+
+        let db = MockDatabase::new(DbBackend::Sqlite).into_connection();
+
+        assert_eq!(
+            db.with_schema("tenant_a").set_search_path().await,
+            Err(DbErr::BackendNotSupported {
+                db: "SQLite",
+                ctx: "Search path",
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -1,5 +1,10 @@
 use crate::DbBackend;
-use sea_query::{inject_parameters, MysqlQueryBuilder, PostgresQueryBuilder, SqliteQueryBuilder};
+use sea_query::{
+    inject_parameters, CommonTableExpression, ConditionHolder, DynIden, EscapeBuilder,
+    MysqlQueryBuilder, OnConflictTarget, OrderExpr, PostgresQueryBuilder, QueryBuilder,
+    QueryStatementBuilder, QuotedBuilder, SelectDistinct, SqlWriter, SqliteQueryBuilder,
+    SubQueryStatement, TableRefBuilder, WithClause,
+};
 pub use sea_query::{Value, Values};
 use std::fmt;
 
@@ -53,6 +58,105 @@ impl Statement {
             db_backend,
         }
     }
+
+    /// Build a statement with its `RETURNING` clause, which the MySQL query builder omits,
+    /// so that it is rendered for MariaDB
+    pub(crate) fn build_with_returning<S>(db_backend: DbBackend, statement: &S) -> Statement
+    where
+        S: QueryStatementBuilder,
+    {
+        let stmt = match db_backend {
+            DbBackend::MySql => statement.build_any(&MariaDbQueryBuilder),
+            DbBackend::Postgres => statement.build_any(&PostgresQueryBuilder),
+            DbBackend::Sqlite => statement.build_any(&SqliteQueryBuilder),
+        };
+        Statement::from_string_values_tuple(db_backend, stmt)
+    }
+}
+
+/// The MySQL query builder, rendering `RETURNING` as supported by MariaDB
+struct MariaDbQueryBuilder;
+
+impl QuotedBuilder for MariaDbQueryBuilder {
+    fn quote(&self) -> char {
+        MysqlQueryBuilder.quote()
+    }
+}
+
+impl EscapeBuilder for MariaDbQueryBuilder {}
+
+impl TableRefBuilder for MariaDbQueryBuilder {}
+
+impl QueryBuilder for MariaDbQueryBuilder {
+    fn values_list_tuple_prefix(&self) -> &str {
+        MysqlQueryBuilder.values_list_tuple_prefix()
+    }
+
+    fn prepare_select_distinct(&self, select_distinct: &SelectDistinct, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_select_distinct(select_distinct, sql)
+    }
+
+    fn prepare_query_statement(&self, query: &SubQueryStatement, sql: &mut dyn SqlWriter) {
+        match query {
+            SubQueryStatement::SelectStatement(stmt) => self.prepare_select_statement(stmt, sql),
+            SubQueryStatement::InsertStatement(stmt) => self.prepare_insert_statement(stmt, sql),
+            SubQueryStatement::UpdateStatement(stmt) => self.prepare_update_statement(stmt, sql),
+            SubQueryStatement::DeleteStatement(stmt) => self.prepare_delete_statement(stmt, sql),
+            SubQueryStatement::WithStatement(stmt) => self.prepare_with_query(stmt, sql),
+        }
+    }
+
+    fn prepare_with_clause_recursive_options(&self, with: &WithClause, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_with_clause_recursive_options(with, sql)
+    }
+
+    fn prepare_with_query_clause_materialization(
+        &self,
+        cte: &CommonTableExpression,
+        sql: &mut dyn SqlWriter,
+    ) {
+        MysqlQueryBuilder.prepare_with_query_clause_materialization(cte, sql)
+    }
+
+    fn prepare_order_expr(&self, order_expr: &OrderExpr, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_order_expr(order_expr, sql)
+    }
+
+    fn prepare_value(&self, value: &Value, sql: &mut dyn SqlWriter) {
+        sql.push_param(value.clone(), self as _);
+    }
+
+    fn prepare_on_conflict_target(
+        &self,
+        target: &Option<OnConflictTarget>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        MysqlQueryBuilder.prepare_on_conflict_target(target, sql)
+    }
+
+    fn prepare_on_conflict_keywords(&self, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_on_conflict_keywords(sql)
+    }
+
+    fn prepare_on_conflict_do_update_keywords(&self, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_on_conflict_do_update_keywords(sql)
+    }
+
+    fn prepare_on_conflict_excluded_table(&self, col: &DynIden, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_on_conflict_excluded_table(col, sql)
+    }
+
+    fn prepare_on_conflict_condition(&self, condition: &ConditionHolder, sql: &mut dyn SqlWriter) {
+        MysqlQueryBuilder.prepare_on_conflict_condition(condition, sql)
+    }
+
+    fn random_function(&self) -> &str {
+        MysqlQueryBuilder.random_function()
+    }
+
+    fn insert_default_keyword(&self) -> &str {
+        MysqlQueryBuilder.insert_default_keyword()
+    }
 }
 
 impl fmt::Display for Statement {
@@ -143,3 +247,55 @@ macro_rules! build_type_stmt {
 build_type_stmt!(sea_query::extension::postgres::TypeAlterStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeCreateStatement);
 build_type_stmt!(sea_query::extension::postgres::TypeDropStatement);
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests_cfg::cake, DbBackend, EntityName, Statement};
+    use pretty_assertions::assert_eq;
+    use sea_query::{Expr, OnConflict, Query};
+
+    #[test]
+    fn build_with_returning() {
+        let insert = Query::insert()
+            .into_table(cake::Entity.table_ref())
+            .columns([cake::Column::Id, cake::Column::Name])
+            .values_panic([1.into(), "Apple Pie".into()])
+            .on_conflict(
+                OnConflict::column(cake::Column::Id)
+                    .update_column(cake::Column::Name)
+                    .to_owned(),
+            )
+            .returning(Query::returning().columns([cake::Column::Id, cake::Column::Name]))
+            .to_owned();
+        assert_eq!(
+            Statement::build_with_returning(DbBackend::MySql, &insert),
+            Statement::from_sql_and_values(
+                DbBackend::MySql,
+                r#"INSERT INTO `cake` (`id`, `name`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`) RETURNING `id`, `name`"#,
+                vec![1.into(), "Apple Pie".into()]
+            )
+        );
+        assert_eq!(
+            Statement::build_with_returning(DbBackend::Postgres, &insert),
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2) ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name" RETURNING "id", "name""#,
+                vec![1.into(), "Apple Pie".into()]
+            )
+        );
+
+        let delete = Query::delete()
+            .from_table(cake::Entity.table_ref())
+            .and_where(Expr::col(cake::Column::Id).eq(1))
+            .returning(Query::returning().columns([cake::Column::Id]))
+            .to_owned();
+        assert_eq!(
+            Statement::build_with_returning(DbBackend::MySql, &delete),
+            Statement::from_sql_and_values(
+                DbBackend::MySql,
+                r#"DELETE FROM `cake` WHERE `id` = ? RETURNING `id`"#,
+                vec![1.into()]
+            )
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use crate::{
+    ColumnTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait, ExecResult, QueryResult,
+    Statement, StreamTrait,
+};
+use sea_query::{SimpleExpr, TableRef, Value};
+use std::{future::Future, pin::Pin};
+
+/// A connection or transaction bound to a tenant, as returned by [`ConnectionTrait::with_tenant`]
+///
+/// Queries issued through it only see the rows of the tenant on Entities having a
+/// [tenant column](crate::EntityTrait::tenant_column), including the related rows of
+/// `find_also_related` and `find_with_related`, and inserts fill that column
+/// with the tenant unless it is set explicitly. Raw statements are executed as they are.
+#[derive(Debug, Clone)]
+pub struct TenantConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    conn: &'c C,
+    tenant: Value,
+}
+
+impl<'c, C> TenantConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    pub(crate) fn new(conn: &'c C, tenant: Value) -> Self {
+        Self { conn, tenant }
+    }
+
+    /// The connection the tenant is bound to
+    pub fn inner(&self) -> &'c C {
+        self.conn
+    }
+
+    /// Set the Postgres setting `name` (e.g. `app.tenant_id`) to the tenant,
+    /// so that row level security policies can refer to it with `current_setting`
+    ///
+    /// The setting is local to the current transaction, so bind the tenant to a
+    /// [`DatabaseTransaction`](crate::DatabaseTransaction) and call this before issuing queries.
+    ///
+    /// Returns [`DbErr::BackendNotSupported`] on other databases.
+    pub async fn set_session_variable(&self, name: &str) -> Result<(), DbErr> {
+        let db_backend = self.get_database_backend();
+        if db_backend != DbBackend::Postgres {
+            return Err(DbErr::BackendNotSupported {
+                db: db_backend.as_str(),
+                ctx: "Session variable",
+            });
+        }
+        self.conn
+            .execute(Statement::from_sql_and_values(
+                db_backend,
+                "SELECT set_config($1, CAST($2 AS TEXT), true)",
+                [name.into(), self.tenant.clone()],
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<'c, C> ConnectionTrait for TenantConnection<'c, C>
+where
+    C: ConnectionTrait,
+{
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.conn.execute(stmt).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.conn.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.conn.query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    fn support_update_returning(&self) -> bool {
+        self.conn.support_update_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+
+    fn tenant(&self) -> Option<Value> {
+        Some(self.tenant.clone())
+    }
+
+    fn schema_name(&self) -> Option<&str> {
+        self.conn.schema_name()
+    }
+}
+
+impl<'c, C> StreamTrait for TenantConnection<'c, C>
+where
+    C: ConnectionTrait + StreamTrait,
+{
+    type Stream<'a>
+        = C::Stream<'a>
+    where
+        Self: 'a;
+
+    fn stream<'a>(
+        &'a self,
+        stmt: Statement,
+    ) -> Pin<Box<dyn Future<Output = Result<Self::Stream<'a>, DbErr>> + 'a + Send>> {
+        self.conn.stream(stmt)
+    }
+}
+
+/// Builds the condition restricting a query to a tenant, for an Entity known only when the query is built
+pub(crate) type TenantFilter = fn(Value) -> Option<SimpleExpr>;
+
+/// The condition restricting the rows of the Entity to the tenant, if the Entity has a tenant column
+pub(crate) fn tenant_filter<E>(tenant: Value) -> Option<SimpleExpr>
+where
+    E: EntityTrait,
+{
+    E::tenant_column().map(|col| col.eq(tenant))
+}
+
+/// A [TenantFilter] for queries which are not restricted to a tenant
+pub(crate) fn no_tenant_filter(_: Value) -> Option<SimpleExpr> {
+    None
+}
+
+/// Builds the conditions restricting the joined tables of a query to a tenant, by table,
+/// for Entities known only when the query is built
+pub(crate) type TenantJoin = fn(Value) -> Vec<(TableRef, SimpleExpr)>;
+
+/// The condition restricting the joined rows of the Entity to the tenant,
+/// if the Entity has a tenant column
+pub(crate) fn tenant_join<E>(tenant: Value) -> Vec<(TableRef, SimpleExpr)>
+where
+    E: EntityTrait,
+{
+    tenant_filter::<E>(tenant)
+        .map(|condition| (E::default().table_ref(), condition))
+        .into_iter()
+        .collect()
+}
+
+/// A [TenantJoin] for queries whose joined tables are not restricted to a tenant
+pub(crate) fn no_tenant_join(_: Value) -> Vec<(TableRef, SimpleExpr)> {
+    Vec::new()
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+    use sea_query::{Expr, OnConflict};
+
+    fn exec_result() -> MockExecResult {
+        MockExecResult {
+            last_insert_id: 0,
+            rows_affected: 1,
+        }
+    }
+
+    #[smol_potat::test]
+    async fn tenant_select() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![invoice::Model {
+                    id: 1,
+                    tenant_id: 7,
+                    total: 10,
+                }],
+                vec![],
+            ])
+            .into_connection();
+        let tenant = db.with_tenant(7);
+
+        invoice::Entity::find_by_id(1).one(&tenant).await?;
+        cake::Entity::find().all(&tenant).await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "invoice"."id", "invoice"."tenant_id", "invoice"."total" FROM "invoice" WHERE "invoice"."id" = $1 AND "invoice"."tenant_id" = $2 LIMIT $3"#,
+                    vec![1i32.into(), 7i32.into(), 1u64.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                    vec![]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn tenant_select_related() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                Vec::<invoice::Model>::new(),
+                Vec::<invoice::Model>::new(),
+            ])
+            .into_connection();
+        let tenant = db.with_tenant(7);
+
+        invoice::Entity::find()
+            .find_also_related(invoice_line::Entity)
+            .all(&tenant)
+            .await?;
+        invoice::Entity::find()
+            .find_with_related(invoice_line::Entity)
+            .all(&tenant)
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "invoice"."id" AS "A_id", "invoice"."tenant_id" AS "A_tenant_id", "invoice"."total" AS "A_total","#,
+                        r#""invoice_line"."id" AS "B_id", "invoice_line"."tenant_id" AS "B_tenant_id","#,
+                        r#""invoice_line"."invoice_id" AS "B_invoice_id", "invoice_line"."amount" AS "B_amount""#,
+                        r#"FROM "invoice" LEFT JOIN "invoice_line" ON "invoice"."id" = "invoice_line"."invoice_id""#,
+                        r#"AND "invoice_line"."tenant_id" = $1 WHERE "invoice"."tenant_id" = $2"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![7i32.into(), 7i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    [
+                        r#"SELECT "invoice"."id" AS "A_id", "invoice"."tenant_id" AS "A_tenant_id", "invoice"."total" AS "A_total","#,
+                        r#""invoice_line"."id" AS "B_id", "invoice_line"."tenant_id" AS "B_tenant_id","#,
+                        r#""invoice_line"."invoice_id" AS "B_invoice_id", "invoice_line"."amount" AS "B_amount""#,
+                        r#"FROM "invoice" LEFT JOIN "invoice_line" ON "invoice"."id" = "invoice_line"."invoice_id""#,
+                        r#"AND "invoice_line"."tenant_id" = $1 WHERE "invoice"."tenant_id" = $2"#,
+                        r#"ORDER BY "invoice"."id" ASC"#,
+                    ]
+                    .join(" ")
+                    .as_str(),
+                    vec![7i32.into(), 7i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn tenant_update_delete() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![exec_result(), exec_result(), exec_result()])
+            .into_connection();
+        let tenant = db.with_tenant(7);
+
+        invoice::Entity::update_many()
+            .col_expr(invoice::Column::Total, Expr::value(0))
+            .exec(&tenant)
+            .await?;
+        invoice::Entity::update_batch([invoice::ActiveModel {
+            id: Unchanged(1),
+            total: Set(20),
+            ..Default::default()
+        }])
+        .exec(&tenant)
+        .await?;
+        invoice::Entity::delete_by_id(1).exec(&tenant).await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"UPDATE "invoice" SET "total" = ? WHERE "invoice"."tenant_id" = ?"#,
+                    vec![0i32.into(), 7i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"UPDATE "invoice" SET "total" = (CASE WHEN ("invoice"."id" = ?) THEN ? ELSE "invoice"."total" END) WHERE "invoice"."id" IN (?) AND "invoice"."tenant_id" = ?"#,
+                    vec![1i32.into(), 20i32.into(), 1i32.into(), 7i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Sqlite,
+                    r#"DELETE FROM "invoice" WHERE "invoice"."id" = ? AND "invoice"."tenant_id" = ?"#,
+                    vec![1i32.into(), 7i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn tenant_update_batch_postgres() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![exec_result()])
+            .into_connection();
+
+        invoice::Entity::update_batch([invoice::ActiveModel {
+            id: Unchanged(1),
+            total: Set(20),
+            ..Default::default()
+        }])
+        .exec(&db.with_tenant(7))
+        .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"UPDATE "invoice" SET "total" = "batch"."total" FROM (VALUES ($1, $2)) AS "batch" ("id", "total")"#,
+                    r#"WHERE "invoice"."id" = "batch"."id" AND "invoice"."tenant_id" = $3"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), 20i32.into(), 7i32.into()]
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn tenant_insert() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![exec_result(), exec_result()])
+            .into_connection();
+        let tenant = db.with_tenant(7);
+
+        invoice::Entity::insert_many([
+            invoice::ActiveModel {
+                id: Set(1),
+                total: Set(10),
+                ..Default::default()
+            },
+            invoice::ActiveModel {
+                id: Set(2),
+                total: Set(20),
+                ..Default::default()
+            },
+        ])
+        .on_conflict(
+            OnConflict::column(invoice::Column::Id)
+                .update_column(invoice::Column::Total)
+                .to_owned(),
+        )
+        .exec_without_returning(&tenant)
+        .await?;
+        invoice::Entity::insert(invoice::ActiveModel {
+            id: Set(3),
+            tenant_id: Set(8),
+            total: Set(30),
+        })
+        .exec_without_returning(&tenant)
+        .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "invoice" ("id", "total", "tenant_id") VALUES ($1, $2, $3), ($4, $5, $6) ON CONFLICT ("id") DO UPDATE SET "total" = "excluded"."total""#,
+                    vec![
+                        1i32.into(),
+                        10i32.into(),
+                        7i32.into(),
+                        2i32.into(),
+                        20i32.into(),
+                        7i32.into()
+                    ]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "invoice" ("id", "tenant_id", "total") VALUES ($1, $2, $3)"#,
+                    vec![3i32.into(), 8i32.into(), 30i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn tenant_session_variable() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_exec_results(vec![exec_result()])
+            .into_connection();
+
+        db.with_tenant(7)
+            .set_session_variable("app.tenant_id")
+            .await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"SELECT set_config($1, CAST($2 AS TEXT), true)"#,
+                vec!["app.tenant_id".into(), 7i32.into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            db.with_tenant(7)
+                .set_session_variable("app.tenant_id")
+                .await,
+            Err(DbErr::BackendNotSupported {
+                db: "MySQL",
+                ctx: "Session variable",
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
 #[cfg(feature = "sqlx-dep")]
 use crate::{sqlx_error_to_exec_err, sqlx_error_to_query_err};
 use futures::lock::Mutex;
+use sea_query::{Alias, Iden};
 #[cfg(feature = "sqlx-dep")]
 use sqlx::{pool::PoolConnection, TransactionManager};
 use std::{future::Future, pin::Pin, sync::Arc};
@@ -16,8 +17,30 @@ use tracing::instrument;
 pub struct DatabaseTransaction {
     conn: Arc<Mutex<InnerConnection>>,
     backend: DbBackend,
+    support_returning: bool,
     open: bool,
     metric_callback: Option<crate::metric::Callback>,
+    hooks: Arc<std::sync::Mutex<Vec<TransactionHooks>>>,
+    depth: usize,
+}
+
+type TransactionHook = Box<dyn FnOnce() + Send>;
+
+/// The callbacks registered on a transaction, or on a savepoint released into it
+#[derive(Default)]
+struct TransactionHooks {
+    on_commit: Vec<TransactionHook>,
+    on_rollback: Vec<TransactionHook>,
+    /// The named savepoints of the transaction, along with the number of callbacks
+    /// registered before each of them
+    savepoints: Vec<(String, usize, usize)>,
+}
+
+impl TransactionHooks {
+    fn append(&mut self, mut other: Self) {
+        self.on_commit.append(&mut other.on_commit);
+        self.on_rollback.append(&mut other.on_rollback);
+    }
 }
 
 impl std::fmt::Debug for DatabaseTransaction {
@@ -31,13 +54,16 @@ impl DatabaseTransaction {
     pub(crate) async fn new_mysql(
         inner: PoolConnection<sqlx::MySql>,
         metric_callback: Option<crate::metric::Callback>,
+        support_returning: bool,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::MySql(inner))),
             DbBackend::MySql,
+            support_returning,
             metric_callback,
+            Default::default(),
             isolation_level,
             access_mode,
         )
@@ -54,7 +80,9 @@ impl DatabaseTransaction {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Postgres(inner))),
             DbBackend::Postgres,
+            true,
             metric_callback,
+            Default::default(),
             isolation_level,
             access_mode,
         )
@@ -65,13 +93,16 @@ impl DatabaseTransaction {
     pub(crate) async fn new_sqlite(
         inner: PoolConnection<sqlx::Sqlite>,
         metric_callback: Option<crate::metric::Callback>,
+        support_returning: bool,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Sqlite(inner))),
             DbBackend::Sqlite,
+            support_returning,
             metric_callback,
+            Default::default(),
             isolation_level,
             access_mode,
         )
@@ -87,27 +118,39 @@ impl DatabaseTransaction {
         Self::begin(
             Arc::new(Mutex::new(InnerConnection::Mock(inner))),
             backend,
+            backend.support_returning(),
             metric_callback,
+            Default::default(),
             None,
             None,
         )
         .await
     }
 
-    #[instrument(level = "trace", skip(metric_callback))]
+    #[instrument(level = "trace", skip(metric_callback, hooks))]
     #[allow(unreachable_code)]
     async fn begin(
         conn: Arc<Mutex<InnerConnection>>,
         backend: DbBackend,
+        support_returning: bool,
         metric_callback: Option<crate::metric::Callback>,
+        hooks: Arc<std::sync::Mutex<Vec<TransactionHooks>>>,
         isolation_level: Option<IsolationLevel>,
         access_mode: Option<AccessMode>,
     ) -> Result<DatabaseTransaction, DbErr> {
+        let depth = {
+            let mut stack = hooks.lock().expect("Failed to acquire transaction hooks");
+            stack.push(TransactionHooks::default());
+            stack.len() - 1
+        };
         let res = DatabaseTransaction {
             conn,
             backend,
+            support_returning,
             open: true,
             metric_callback,
+            hooks,
+            depth,
         };
         match *res.conn.lock().await {
             #[cfg(feature = "sqlx-mysql")]
@@ -201,6 +244,7 @@ impl DatabaseTransaction {
             }
         }
         self.open = false;
+        self.finish(true);
         Ok(())
     }
 
@@ -233,6 +277,157 @@ impl DatabaseTransaction {
             }
         }
         self.open = false;
+        self.finish(false);
+        Ok(())
+    }
+
+    /// Set a named savepoint, which the transaction can be rolled back to without being aborted.
+    /// Setting a savepoint with the name of an existing one hides it until the new one is released.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results(vec![
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 0,
+    /// #         };
+    /// #         4
+    /// #     ])
+    /// #     .append_exec_errors(vec![DbErr::Custom("Duplicated name".to_owned())])
+    /// #     .append_exec_results(vec![MockExecResult {
+    /// #         last_insert_id: 0,
+    /// #         rows_affected: 0,
+    /// #     }])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let txn = db.begin().await?;
+    /// for name in ["Apple Pie", "Apple Pie"] {
+    ///     txn.savepoint("cake").await?;
+    ///     let res = cake::Entity::insert(cake::ActiveModel {
+    ///         name: Set(name.to_owned()),
+    ///         ..Default::default()
+    ///     })
+    ///     .exec_without_returning(&txn)
+    ///     .await;
+    ///     match res {
+    ///         Ok(_) => txn.release("cake").await?,
+    ///         Err(_) => txn.rollback_to("cake").await?,
+    ///     }
+    /// }
+    /// txn.commit().await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::many(vec![
+    ///         Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+    ///         Statement::from_string(DbBackend::Postgres, r#"SAVEPOINT "cake""#.to_owned()),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+    ///             vec!["Apple Pie".into()]
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, r#"RELEASE SAVEPOINT "cake""#.to_owned()),
+    ///         Statement::from_string(DbBackend::Postgres, r#"SAVEPOINT "cake""#.to_owned()),
+    ///         Statement::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+    ///             vec!["Apple Pie".into()]
+    ///         ),
+    ///         Statement::from_string(
+    ///             DbBackend::Postgres,
+    ///             r#"ROLLBACK TO SAVEPOINT "cake""#.to_owned()
+    ///         ),
+    ///         Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+    ///     ])]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(level = "trace")]
+    pub async fn savepoint(&self, name: &str) -> Result<(), DbErr> {
+        self.execute_savepoint("SAVEPOINT", name).await?;
+        let mut stack = self
+            .hooks
+            .lock()
+            .expect("Failed to acquire transaction hooks");
+        if let Some(hooks) = stack.get_mut(self.depth) {
+            let (on_commit, on_rollback) = (hooks.on_commit.len(), hooks.on_rollback.len());
+            hooks
+                .savepoints
+                .push((name.to_owned(), on_commit, on_rollback));
+        }
+        Ok(())
+    }
+
+    /// Roll back the changes made since the named savepoint, which is kept so that it can
+    /// be rolled back to again. The savepoints set after it are released. The [on_commit]
+    /// callbacks registered since are discarded, and the [on_rollback] ones are run.
+    ///
+    /// [on_commit]: DatabaseTransaction::on_commit
+    /// [on_rollback]: DatabaseTransaction::on_rollback
+    #[instrument(level = "trace")]
+    pub async fn rollback_to(&self, name: &str) -> Result<(), DbErr> {
+        self.execute_savepoint("ROLLBACK TO SAVEPOINT", name)
+            .await?;
+        let callbacks = {
+            let mut stack = self
+                .hooks
+                .lock()
+                .expect("Failed to acquire transaction hooks");
+            match stack.get_mut(self.depth) {
+                Some(hooks) => match hooks.savepoints.iter().rposition(|(n, ..)| n == name) {
+                    Some(i) => {
+                        let (_, on_commit, on_rollback) = hooks.savepoints[i];
+                        hooks.savepoints.truncate(i + 1);
+                        hooks.on_commit.truncate(on_commit);
+                        hooks.on_rollback.split_off(on_rollback)
+                    }
+                    None => Vec::new(),
+                },
+                None => Vec::new(),
+            }
+        };
+        for callback in callbacks {
+            callback();
+        }
+        Ok(())
+    }
+
+    /// Release the named savepoint, and the savepoints set after it, keeping the changes
+    /// made since. The changes are still undone if the transaction is rolled back.
+    #[instrument(level = "trace")]
+    pub async fn release(&self, name: &str) -> Result<(), DbErr> {
+        self.execute_savepoint("RELEASE SAVEPOINT", name).await?;
+        let mut stack = self
+            .hooks
+            .lock()
+            .expect("Failed to acquire transaction hooks");
+        if let Some(hooks) = stack.get_mut(self.depth) {
+            if let Some(i) = hooks.savepoints.iter().rposition(|(n, ..)| n == name) {
+                hooks.savepoints.truncate(i);
+            }
+        }
+        Ok(())
+    }
+
+    async fn execute_savepoint(&self, command: &str, name: &str) -> Result<(), DbErr> {
+        let quote = match self.backend {
+            DbBackend::MySql => '`',
+            DbBackend::Postgres | DbBackend::Sqlite => '"',
+        };
+        let mut sql = format!("{} ", command);
+        Alias::new(name).prepare(&mut sql, quote);
+        self.execute(Statement::from_string(self.backend, sql))
+            .await?;
         Ok(())
     }
 
@@ -265,6 +460,72 @@ impl DatabaseTransaction {
                 //this should never happen
                 panic!("Dropping a locked Transaction");
             }
+            self.finish(false);
+        }
+    }
+
+    /// Register a callback to run once the transaction is committed. If the transaction
+    /// is nested, the callback waits for the outermost transaction to commit, and is
+    /// discarded if any of them is rolled back.
+    pub fn on_commit<F>(&self, callback: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let mut stack = self
+            .hooks
+            .lock()
+            .expect("Failed to acquire transaction hooks");
+        if let Some(hooks) = stack.get_mut(self.depth) {
+            hooks.on_commit.push(Box::new(callback));
+        }
+    }
+
+    /// Register a callback to run once the transaction is rolled back, explicitly
+    /// or when it is dropped. If the transaction is nested, the callback also runs
+    /// when an enclosing transaction is rolled back after it has been committed.
+    pub fn on_rollback<F>(&self, callback: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let mut stack = self
+            .hooks
+            .lock()
+            .expect("Failed to acquire transaction hooks");
+        if let Some(hooks) = stack.get_mut(self.depth) {
+            hooks.on_rollback.push(Box::new(callback));
+        }
+    }
+
+    /// Runs the callbacks of the transaction, and of the nested ones still in it,
+    /// unless it is committed into an enclosing transaction which takes them over
+    fn finish(&self, committed: bool) {
+        let hooks = {
+            let mut stack = self
+                .hooks
+                .lock()
+                .expect("Failed to acquire transaction hooks");
+            if self.depth >= stack.len() {
+                return;
+            }
+            let mut hooks = TransactionHooks::default();
+            for nested in stack.split_off(self.depth) {
+                hooks.append(nested);
+            }
+            match stack.last_mut() {
+                Some(parent) if committed => {
+                    parent.append(hooks);
+                    return;
+                }
+                _ => hooks,
+            }
+        };
+        let callbacks = if committed {
+            hooks.on_commit
+        } else {
+            hooks.on_rollback
+        };
+        for callback in callbacks {
+            callback();
         }
     }
 
@@ -293,6 +554,10 @@ impl ConnectionTrait for DatabaseTransaction {
         self.backend
     }
 
+    fn support_returning(&self) -> bool {
+        self.support_returning
+    }
+
     #[instrument(level = "trace")]
     #[allow(unused_variables)]
     async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
@@ -443,7 +708,9 @@ impl TransactionTrait for DatabaseTransaction {
         DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
+            self.support_returning,
             self.metric_callback.clone(),
+            Arc::clone(&self.hooks),
             None,
             None,
         )
@@ -459,7 +726,9 @@ impl TransactionTrait for DatabaseTransaction {
         DatabaseTransaction::begin(
             Arc::clone(&self.conn),
             self.backend,
+            self.support_returning,
             self.metric_callback.clone(),
+            Arc::clone(&self.hooks),
             isolation_level,
             access_mode,
         )
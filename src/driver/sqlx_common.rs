@@ -14,3 +14,30 @@ pub fn sqlx_error_to_query_err(err: sqlx::Error) -> DbErr {
 pub fn sqlx_error_to_conn_err(err: sqlx::Error) -> DbErr {
     DbErr::Conn(RuntimeErr::SqlxError(err))
 }
+
+/// Parse the leading `major.minor` of a server version string, e.g. `10.6.11-MariaDB`
+pub(crate) fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version
+        .split(|c: char| !c.is_ascii_digit())
+        .map(|part| part.parse().ok());
+    match (parts.next(), parts.next()) {
+        (Some(Some(major)), Some(Some(minor))) => Some((major, minor)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_version;
+
+    #[test]
+    fn parse_server_version() {
+        assert_eq!(parse_version("3.39.2"), Some((3, 39)));
+        assert_eq!(parse_version("8.0.31"), Some((8, 0)));
+        assert_eq!(
+            parse_version("10.6.11-MariaDB-1:10.6.11+maria~ubu2004"),
+            Some((10, 6))
+        );
+        assert_eq!(parse_version("unknown"), None);
+    }
+}
@@ -4,7 +4,7 @@ use std::{future::Future, pin::Pin, sync::Arc};
 use sqlx::{
     mysql::{MySqlConnectOptions, MySqlQueryResult, MySqlRow},
     pool::PoolConnection,
-    Executor, MySql, MySqlPool,
+    Executor, MySql, MySqlPool, Row,
 };
 
 use sea_query_binder::SqlxValues;
@@ -26,6 +26,7 @@ pub struct SqlxMySqlConnector;
 pub struct SqlxMySqlPoolConnection {
     pool: MySqlPool,
     metric_callback: Option<crate::metric::Callback>,
+    support_returning: bool,
 }
 
 impl std::fmt::Debug for SqlxMySqlPoolConnection {
@@ -54,12 +55,16 @@ impl SqlxMySqlConnector {
             opt.log_statements(options.sqlx_logging_level);
         }
         match options.pool_options().connect_with(opt).await {
-            Ok(pool) => Ok(DatabaseConnection::SqlxMySqlPoolConnection(
-                SqlxMySqlPoolConnection {
-                    pool,
-                    metric_callback: None,
-                },
-            )),
+            Ok(pool) => {
+                let support_returning = support_returning(&pool).await?;
+                Ok(DatabaseConnection::SqlxMySqlPoolConnection(
+                    SqlxMySqlPoolConnection {
+                        pool,
+                        metric_callback: None,
+                        support_returning,
+                    },
+                ))
+            }
             Err(e) => Err(sqlx_error_to_conn_err(e)),
         }
     }
@@ -67,10 +72,13 @@ impl SqlxMySqlConnector {
 
 impl SqlxMySqlConnector {
     /// Instantiate a sqlx pool connection to a [DatabaseConnection]
+    ///
+    /// The server version is not detected, so `RETURNING` will not be used
     pub fn from_sqlx_mysql_pool(pool: MySqlPool) -> DatabaseConnection {
         DatabaseConnection::SqlxMySqlPoolConnection(SqlxMySqlPoolConnection {
             pool,
             metric_callback: None,
+            support_returning: false,
         })
     }
 }
@@ -160,6 +168,7 @@ impl SqlxMySqlPoolConnection {
             DatabaseTransaction::new_mysql(
                 conn,
                 self.metric_callback.clone(),
+                self.support_returning,
                 isolation_level,
                 access_mode,
             )
@@ -189,6 +198,7 @@ impl SqlxMySqlPoolConnection {
             let transaction = DatabaseTransaction::new_mysql(
                 conn,
                 self.metric_callback.clone(),
+                self.support_returning,
                 isolation_level,
                 access_mode,
             )
@@ -200,6 +210,11 @@ impl SqlxMySqlPoolConnection {
         }
     }
 
+    /// Check if the server supports `RETURNING` syntax on insert and delete, i.e. is MariaDB 10.5 or later
+    pub fn support_returning(&self) -> bool {
+        self.support_returning
+    }
+
     pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
@@ -229,6 +244,16 @@ impl From<MySqlQueryResult> for ExecResult {
     }
 }
 
+async fn support_returning(pool: &MySqlPool) -> Result<bool, DbErr> {
+    let version: String = sqlx::query("SELECT VERSION()")
+        .fetch_one(pool)
+        .await
+        .and_then(|row| row.try_get(0))
+        .map_err(sqlx_error_to_conn_err)?;
+    Ok(version.contains("MariaDB")
+        && matches!(parse_version(&version), Some(version) if version >= (10, 5)))
+}
+
 pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, MySql, SqlxValues> {
     let values = stmt
         .values
@@ -4,7 +4,7 @@ use std::{future::Future, pin::Pin, sync::Arc};
 use sqlx::{
     pool::PoolConnection,
     sqlite::{SqliteConnectOptions, SqliteQueryResult, SqliteRow},
-    Sqlite, SqlitePool,
+    Row, Sqlite, SqlitePool,
 };
 
 use sea_query_binder::SqlxValues;
@@ -26,6 +26,7 @@ pub struct SqlxSqliteConnector;
 pub struct SqlxSqlitePoolConnection {
     pool: SqlitePool,
     metric_callback: Option<crate::metric::Callback>,
+    support_returning: bool,
 }
 
 impl std::fmt::Debug for SqlxSqlitePoolConnection {
@@ -61,12 +62,16 @@ impl SqlxSqliteConnector {
             options.max_connections(1);
         }
         match options.pool_options().connect_with(opt).await {
-            Ok(pool) => Ok(DatabaseConnection::SqlxSqlitePoolConnection(
-                SqlxSqlitePoolConnection {
-                    pool,
-                    metric_callback: None,
-                },
-            )),
+            Ok(pool) => {
+                let support_returning = support_returning(&pool).await?;
+                Ok(DatabaseConnection::SqlxSqlitePoolConnection(
+                    SqlxSqlitePoolConnection {
+                        pool,
+                        metric_callback: None,
+                        support_returning,
+                    },
+                ))
+            }
             Err(e) => Err(sqlx_error_to_conn_err(e)),
         }
     }
@@ -74,10 +79,13 @@ impl SqlxSqliteConnector {
 
 impl SqlxSqliteConnector {
     /// Instantiate a sqlx pool connection to a [DatabaseConnection]
+    ///
+    /// The SQLite version is not detected, so `RETURNING` will not be used
     pub fn from_sqlx_sqlite_pool(pool: SqlitePool) -> DatabaseConnection {
         DatabaseConnection::SqlxSqlitePoolConnection(SqlxSqlitePoolConnection {
             pool,
             metric_callback: None,
+            support_returning: false,
         })
     }
 }
@@ -167,6 +175,7 @@ impl SqlxSqlitePoolConnection {
             DatabaseTransaction::new_sqlite(
                 conn,
                 self.metric_callback.clone(),
+                self.support_returning,
                 isolation_level,
                 access_mode,
             )
@@ -196,6 +205,7 @@ impl SqlxSqlitePoolConnection {
             let transaction = DatabaseTransaction::new_sqlite(
                 conn,
                 self.metric_callback.clone(),
+                self.support_returning,
                 isolation_level,
                 access_mode,
             )
@@ -207,6 +217,11 @@ impl SqlxSqlitePoolConnection {
         }
     }
 
+    /// Check if `RETURNING` syntax is used, i.e. the SQLite library is version 3.35 or later
+    pub fn support_returning(&self) -> bool {
+        self.support_returning
+    }
+
     pub(crate) fn set_metric_callback<F>(&mut self, callback: F)
     where
         F: Fn(&crate::metric::Info<'_>) + Send + Sync + 'static,
@@ -236,6 +251,15 @@ impl From<SqliteQueryResult> for ExecResult {
     }
 }
 
+async fn support_returning(pool: &SqlitePool) -> Result<bool, DbErr> {
+    let version: String = sqlx::query("SELECT sqlite_version()")
+        .fetch_one(pool)
+        .await
+        .and_then(|row| row.try_get(0))
+        .map_err(sqlx_error_to_conn_err)?;
+    Ok(matches!(parse_version(&version), Some(version) if version >= (3, 35)))
+}
+
 pub(crate) fn sqlx_query(stmt: &Statement) -> sqlx::query::Query<'_, Sqlite, SqlxValues> {
     let values = stmt
         .values
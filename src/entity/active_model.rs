@@ -1,8 +1,9 @@
 use crate::{
-    error::*, ConnectionTrait, DeleteResult, EntityTrait, Iterable, PrimaryKeyToColumn, Value,
+    error::*, ColumnTrait, ConnectionTrait, DbBackend, DeleteResult, EntityTrait, IdenStatic,
+    Iterable, PrimaryKeyToColumn, QueryFilter, Value,
 };
 use async_trait::async_trait;
-use sea_query::{Nullable, ValueTuple};
+use sea_query::{Condition, Nullable, ValueTuple};
 use std::fmt::Debug;
 
 pub use ActiveValue::NotSet;
@@ -278,11 +279,137 @@ pub trait ActiveModelTrait: Clone + Debug {
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
-        let am = ActiveModelBehavior::before_save(self, true)?;
+        let am = ActiveModelBehavior::before_save_with_db(self, db, true).await?;
         let model = <Self::Entity as EntityTrait>::insert(am)
             .exec_with_returning(db)
             .await?;
-        Self::after_save(model, true)
+        Self::after_save_with_db(model, db, true).await
+    }
+
+    /// Perform an `INSERT` operation on the ActiveModel, or update the `Set` columns of the row
+    /// conflicting with its primary key, or with a unique column if the primary key is not set.
+    /// The resulting model is fetched with `RETURNING` if supported, or else with a `SELECT`,
+    /// and returned along with whether it was inserted.
+    ///
+    /// On Postgres, whether the row was inserted is returned by the upsert statement itself.
+    /// On other databases the conflicting row is looked up beforehand, so a row inserted
+    /// concurrently in between is reported as inserted.
+    /// `before_save` is called with `insert` set to `true`, as the outcome is not known yet.
+    ///
+    /// MySQL has no conflict target, `ON DUPLICATE KEY UPDATE` updates the row conflicting with
+    /// any unique key, so the row is looked up by the primary key or any unique column `Set`.
+    ///
+    /// # Example (MySQL)
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::MySql)
+    /// #     .append_exec_results(vec![
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 2,
+    /// #         },
+    /// #     ])
+    /// #     .append_query_results(vec![
+    /// #         vec![cake::Model {
+    /// #             id: 15,
+    /// #             name: "Apple".to_owned(),
+    /// #         }],
+    /// #         vec![cake::Model {
+    /// #             id: 15,
+    /// #             name: "Apple Pie".to_owned(),
+    /// #         }],
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let apple = cake::ActiveModel {
+    ///     id: Set(15),
+    ///     name: Set("Apple Pie".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     apple.upsert(&db).await?,
+    ///     (
+    ///         cake::Model {
+    ///             id: 15,
+    ///             name: "Apple Pie".to_owned(),
+    ///         },
+    ///         false
+    ///     )
+    /// );
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ?"#,
+    ///             vec![15.into(), 1u64.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"INSERT INTO `cake` (`id`, `name`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `name` = VALUES(`name`)"#,
+    ///             vec![15.into(), "Apple Pie".into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` = ? LIMIT ?"#,
+    ///             vec![15.into(), 1u64.into()]
+    ///         )
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn upsert<'a, C>(
+        self,
+        db: &'a C,
+    ) -> Result<(<Self::Entity as EntityTrait>::Model, bool), DbErr>
+    where
+        <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
+        Self: ActiveModelBehavior + 'a,
+        C: ConnectionTrait,
+    {
+        let am = ActiveModelBehavior::before_save_with_db(self, db, true).await?;
+        let insert = <Self::Entity as EntityTrait>::insert(am.clone());
+        let target = insert.upsert_target().ok_or_else(|| {
+            DbErr::Custom(
+                "Neither the primary key nor any unique column is set for upsert".to_owned(),
+            )
+        })?;
+        let insert = insert.upsert_on(target.clone());
+        let (model, inserted) = match db.get_database_backend() {
+            DbBackend::Postgres => insert.exec_upsert_with_returning(db).await?,
+            backend => {
+                let select = <Self::Entity as EntityTrait>::unscoped()
+                    .filter(upsert_condition(&am, &target, backend));
+                let inserted = select.clone().one(db).await?.is_none();
+                let model = if db.support_returning() {
+                    insert.exec_with_returning(db).await?
+                } else {
+                    insert.exec_without_returning(db).await?;
+                    match select.one(db).await? {
+                        Some(model) => model,
+                        None => {
+                            return Err(DbErr::RecordNotFound(
+                                "Failed to find upserted item".to_owned(),
+                            ))
+                        }
+                    }
+                };
+                (model, inserted)
+            }
+        };
+        let model = Self::after_save_with_db(model, db, inserted).await?;
+        Ok((model, inserted))
     }
 
     /// Perform the `UPDATE` operation on an ActiveModel
@@ -400,9 +527,9 @@ pub trait ActiveModelTrait: Clone + Debug {
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
-        let am = ActiveModelBehavior::before_save(self, false)?;
+        let am = ActiveModelBehavior::before_save_with_db(self, db, false).await?;
         let model: <Self::Entity as EntityTrait>::Model = Self::Entity::update(am).exec(db).await?;
-        Self::after_save(model, false)
+        Self::after_save_with_db(model, db, false).await
     }
 
     /// Insert the model if primary key is `NotSet`, update otherwise.
@@ -477,10 +604,10 @@ pub trait ActiveModelTrait: Clone + Debug {
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
-        let am = ActiveModelBehavior::before_delete(self)?;
+        let am = ActiveModelBehavior::before_delete_with_db(self, db).await?;
         let am_clone = am.clone();
         let delete_res = Self::Entity::delete(am).exec(db).await?;
-        ActiveModelBehavior::after_delete(am_clone)?;
+        ActiveModelBehavior::after_delete_with_db(am_clone, db).await?;
         Ok(delete_res)
     }
 
@@ -583,6 +710,7 @@ pub trait ActiveModelTrait: Clone + Debug {
 /// impl ActiveModelBehavior for ActiveModel {}
 /// ```
 /// See module level docs [crate::entity] for a full example
+#[async_trait]
 #[allow(unused_variables)]
 pub trait ActiveModelBehavior: ActiveModelTrait {
     /// Create a new ActiveModel with default values. Also used by `Default::default()`.
@@ -612,6 +740,47 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     fn after_delete(self) -> Result<Self, DbErr> {
         Ok(self)
     }
+
+    /// Will be called before saving, with the connection the model is saved on,
+    /// so that the queries of the hook run in the same transaction.
+    /// Calls [ActiveModelBehavior::before_save] unless overridden.
+    async fn before_save_with_db<C>(self, db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Self::before_save(self, insert)
+    }
+
+    /// Will be called after saving, with the connection the model is saved on.
+    /// Calls [ActiveModelBehavior::after_save] unless overridden.
+    async fn after_save_with_db<C>(
+        model: <Self::Entity as EntityTrait>::Model,
+        db: &C,
+        insert: bool,
+    ) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Self::after_save(model, insert)
+    }
+
+    /// Will be called before deleting, with the connection the model is deleted on.
+    /// Calls [ActiveModelBehavior::before_delete] unless overridden.
+    async fn before_delete_with_db<C>(self, db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Self::before_delete(self)
+    }
+
+    /// Will be called after deleting, with the connection the model is deleted on.
+    /// Calls [ActiveModelBehavior::after_delete] unless overridden.
+    async fn after_delete_with_db<C>(self, db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        Self::after_delete(self)
+    }
 }
 
 /// A Trait for any type that can be converted into an ActiveModel
@@ -860,6 +1029,31 @@ where
     }
 }
 
+/// The condition matching the row an upsert of the ActiveModel conflicts with
+fn upsert_condition<A>(
+    am: &A,
+    target: &[<A::Entity as EntityTrait>::Column],
+    backend: DbBackend,
+) -> Condition
+where
+    A: ActiveModelTrait,
+{
+    let eq = |col: <A::Entity as EntityTrait>::Column| {
+        am.get(col).into_value().map(|value| col.eq(value))
+    };
+    let condition = target
+        .iter()
+        .filter_map(|col| eq(*col))
+        .fold(Condition::all(), Condition::add);
+    if backend != DbBackend::MySql {
+        return condition;
+    }
+    <A::Entity as EntityTrait>::Column::iter()
+        .filter(|col| col.def().unique && !target.iter().any(|t| t.as_str() == col.as_str()))
+        .filter_map(eq)
+        .fold(Condition::any().add(condition), Condition::add)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{entity::*, tests_cfg::*, DbErr};
@@ -1264,6 +1458,284 @@ mod tests {
         Ok(())
     }
 
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn test_active_model_behavior_with_db() -> Result<(), DbErr> {
+        use crate::*;
+
+        mod my_fruit {
+            use crate as sea_orm;
+            use crate::{entity::prelude::*, ConnectionTrait, Statement};
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "fruit")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                pub cake_id: Option<i32>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            #[async_trait::async_trait]
+            impl ActiveModelBehavior for ActiveModel {
+                async fn before_save_with_db<C>(self, db: &C, insert: bool) -> Result<Self, DbErr>
+                where
+                    C: ConnectionTrait,
+                {
+                    let name = self.name.as_ref().to_owned();
+                    let same_name = Entity::find()
+                        .filter(Column::Name.eq(name.as_str()))
+                        .count(db)
+                        .await?;
+                    if insert && same_name > 0 {
+                        return Err(DbErr::Custom(format!("{} already exists", name)));
+                    }
+                    Ok(self)
+                }
+
+                async fn after_delete_with_db<C>(self, db: &C) -> Result<Self, DbErr>
+                where
+                    C: ConnectionTrait,
+                {
+                    db.execute(Statement::from_sql_and_values(
+                        db.get_database_backend(),
+                        r#"INSERT INTO "audit" ("action") VALUES ($1)"#,
+                        ["delete fruit".into()],
+                    ))
+                    .await?;
+                    Ok(self)
+                }
+            }
+        }
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![maplit::btreemap! {
+                    "num_items" => Into::<Value>::into(0i64),
+                }],
+                vec![maplit::btreemap! {
+                    "id" => Into::<Value>::into(1),
+                    "name" => Into::<Value>::into("Apple"),
+                    "cake_id" => Into::<Value>::into(Option::<i32>::None),
+                }],
+                vec![maplit::btreemap! {
+                    "num_items" => Into::<Value>::into(1i64),
+                }],
+            ])
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+        let count_apple = Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            [
+                r#"SELECT COUNT(*) AS num_items FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+                r#"FROM "fruit" WHERE "fruit"."name" = $1) AS "sub_query""#,
+            ]
+            .join(" ")
+            .as_str(),
+            vec!["Apple".into()],
+        );
+
+        let txn = db.begin().await?;
+        let apple = my_fruit::ActiveModel {
+            name: Set("Apple".to_owned()),
+            ..Default::default()
+        };
+        let model = apple.clone().insert(&txn).await?;
+        assert_eq!(
+            apple.insert(&txn).await,
+            Err(DbErr::Custom("Apple already exists".to_owned()))
+        );
+        model.into_active_model().delete(&txn).await?;
+        txn.commit().await?;
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::many(vec![
+                Statement::from_string(DbBackend::Postgres, "BEGIN".to_owned()),
+                count_apple.clone(),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "fruit" ("name") VALUES ($1) RETURNING "id", "name", "cake_id""#,
+                    vec!["Apple".into()],
+                ),
+                count_apple,
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"DELETE FROM "fruit" WHERE "fruit"."id" = $1"#,
+                    vec![1i32.into()],
+                ),
+                Statement::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"INSERT INTO "audit" ("action") VALUES ($1)"#,
+                    vec!["delete fruit".into()],
+                ),
+                Statement::from_string(DbBackend::Postgres, "COMMIT".to_owned()),
+            ])]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "macros")]
+    async fn test_active_model_upsert_after_save() -> Result<(), DbErr> {
+        use crate::*;
+
+        mod my_fruit {
+            use crate as sea_orm;
+            use crate::{entity::prelude::*, ConnectionTrait};
+
+            #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+            #[sea_orm(table_name = "fruit")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub name: String,
+                pub cake_id: Option<i32>,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            #[async_trait::async_trait]
+            impl ActiveModelBehavior for ActiveModel {
+                async fn after_save_with_db<C>(
+                    mut model: Model,
+                    _: &C,
+                    insert: bool,
+                ) -> Result<Model, DbErr>
+                where
+                    C: ConnectionTrait,
+                {
+                    let outcome = if insert { "inserted" } else { "updated" };
+                    model.name = format!("{} ({})", model.name, outcome);
+                    Ok(model)
+                }
+            }
+        }
+
+        let apple = my_fruit::Model {
+            id: 1,
+            name: "Apple".to_owned(),
+            cake_id: None,
+        };
+        let am = || my_fruit::ActiveModel {
+            id: Set(1),
+            name: Set("Apple".to_owned()),
+            cake_id: Set(None),
+        };
+
+        let row = |inserted: bool| {
+            std::collections::BTreeMap::from([
+                ("id", Into::<Value>::into(1)),
+                ("name", "Apple".into()),
+                ("cake_id", Option::<i32>::None.into()),
+                ("upsert_inserted", inserted.into()),
+            ])
+        };
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![row(true)], vec![row(false)]])
+            .into_connection();
+        for (outcome, inserted) in [("inserted", true), ("updated", false)] {
+            assert_eq!(
+                am().upsert(&db).await?,
+                (
+                    my_fruit::Model {
+                        name: format!("Apple ({})", outcome),
+                        ..apple.clone()
+                    },
+                    inserted
+                )
+            );
+        }
+        assert_eq!(
+            db.into_transaction_log()[0],
+            Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"INSERT INTO "fruit" ("id", "name", "cake_id") VALUES ($1, $2, $3) ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name", "cake_id" = "excluded"."cake_id" RETURNING "id", "name", "cake_id", (xmax = 0) AS upsert_inserted"#,
+                vec![1.into(), "Apple".into(), Option::<i32>::None.into()]
+            )
+        );
+
+        let db = MockDatabase::new(DbBackend::Sqlite)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 1,
+                    rows_affected: 1,
+                };
+                2
+            ])
+            .append_query_results(vec![
+                vec![],
+                vec![apple.clone()],
+                vec![apple.clone()],
+                vec![apple.clone()],
+            ])
+            .into_connection();
+        for (outcome, inserted) in [("inserted", true), ("updated", false)] {
+            assert_eq!(
+                am().upsert(&db).await?,
+                (
+                    my_fruit::Model {
+                        name: format!("Apple ({})", outcome),
+                        ..apple.clone()
+                    },
+                    inserted
+                )
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_active_model_upsert_condition() {
+        use crate::{DbBackend, EntityTrait, QueryFilter, QueryTrait};
+
+        let am = indexes::ActiveModel {
+            indexes_id: Set(1),
+            unique_attr: Set(2),
+            index1_attr: Set(3),
+            index2_attr: NotSet,
+        };
+        let target = [indexes::Column::IndexesId];
+        let select = |backend| {
+            indexes::Entity::find()
+                .filter(super::upsert_condition(&am, &target, backend))
+                .build(backend)
+                .to_string()
+        };
+
+        assert_eq!(
+            select(DbBackend::Postgres),
+            [
+                r#"SELECT "indexes"."indexes_id", "indexes"."unique_attr", "indexes"."index1_attr", "indexes"."index2_attr""#,
+                r#"FROM "public"."indexes" WHERE "indexes"."indexes_id" = 1"#,
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            select(DbBackend::MySql),
+            [
+                "SELECT `indexes`.`indexes_id`, `indexes`.`unique_attr`, `indexes`.`index1_attr`, `indexes`.`index2_attr`",
+                "FROM `public`.`indexes` WHERE `indexes`.`indexes_id` = 1 OR `indexes`.`unique_attr` = 2",
+            ]
+            .join(" ")
+        );
+    }
+
     #[test]
     fn test_active_model_is_changed() {
         let mut fruit: fruit::ActiveModel = Default::default();
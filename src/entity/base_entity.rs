@@ -1,9 +1,9 @@
 use crate::{
     ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne, FromQueryResult, Insert,
     ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related, RelationBuilder,
-    RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    RelationTrait, RelationType, Select, Update, UpdateBatch, UpdateMany, UpdateOne,
 };
-use sea_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
+use sea_query::{Alias, Condition, Expr, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
 use std::fmt::Debug;
 pub use strum::IntoEnumIterator as Iterable;
 
@@ -62,6 +62,81 @@ pub trait EntityTrait: EntityName {
     #[allow(missing_docs)]
     type PrimaryKey: PrimaryKeyTrait + PrimaryKeyToColumn<Column = Self::Column>;
 
+    /// The nullable timestamp column marking a row as soft deleted,
+    /// as specified by `#[sea_orm(soft_delete = "deleted_at")]`
+    ///
+    /// Rows of a soft deleting Entity are hidden from [`EntityTrait::find`] once the column is set,
+    /// and deleting them sets the column to the current timestamp instead of removing the row.
+    fn soft_delete_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// The integer column used for optimistic locking, as marked by `#[sea_orm(version)]`
+    ///
+    /// Updating a model of a versioned Entity increments the column, and only succeeds if
+    /// the row still holds the version the model was read with;
+    /// otherwise [`DbErr::StaleRecord`](crate::DbErr::StaleRecord) is returned.
+    fn version_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// The column filled with the current time on insert, as marked by `#[sea_orm(created_at)]`
+    fn created_at_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// The column filled with the current time on insert and update, as marked by `#[sea_orm(updated_at)]`
+    ///
+    /// It is also filled by [`UpdateMany`] unless the column is set explicitly.
+    fn updated_at_column() -> Option<Self::Column> {
+        None
+    }
+
+    /// The conditions added to every query on the Entity, as specified by
+    /// `#[sea_orm(default_scope = "path::to::fn")]`, where the function returns a
+    /// [`Condition`] or an `Option<Condition>`
+    ///
+    /// The scope applies to the selects from [`EntityTrait::find`], `find_related`, `find_linked`,
+    /// to the related Entity joined by `find_also_related` and `find_with_related`,
+    /// and to the updates and deletes built by the Entity.
+    /// Use [`EntityTrait::unscoped`], or the [`Update`] and [`Delete`] builders, to bypass it.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, sea_query::Expr, tests_cfg::cookie, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     cookie::Entity::find().build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT "cookie"."id", "cookie"."name", "cookie"."discontinued", "cookie"."cake_id" FROM "cookie" WHERE "cookie"."discontinued" = FALSE"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cookie::Entity::update_many()
+    ///         .col_expr(cookie::Column::Name, Expr::value("Cookie"))
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"UPDATE "cookie" SET "name" = 'Cookie' WHERE "cookie"."discontinued" = FALSE"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cookie::Entity::unscoped()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cookie"."id", "cookie"."name", "cookie"."discontinued", "cookie"."cake_id" FROM "cookie""#
+    /// );
+    /// ```
+    fn default_scope() -> Option<Condition> {
+        None
+    }
+
+    /// The column holding the tenant a row belongs to, as marked by `#[sea_orm(tenant)]`
+    ///
+    /// Through a connection bound to a tenant by [`ConnectionTrait::with_tenant`](crate::ConnectionTrait::with_tenant),
+    /// selects, updates and deletes on the Entity only see the rows of that tenant,
+    /// and inserts fill the column with the tenant unless it is set explicitly.
+    fn tenant_column() -> Option<Self::Column> {
+        None
+    }
+
     /// Check if the relation belongs to an Entity
     fn belongs_to<R>(related: R) -> RelationBuilder<Self, R>
     where
@@ -166,9 +241,65 @@ pub trait EntityTrait: EntityName {
     /// # }
     /// ```
     fn find() -> Select<Self> {
+        let select = Self::with_trashed();
+        match Self::soft_delete_column() {
+            Some(col) => select.filter(col.is_null()),
+            None => select,
+        }
+    }
+
+    /// Find all models, ignoring the default scope and including the soft deleted ones
+    fn unscoped() -> Select<Self> {
         Select::new()
     }
 
+    /// Find all models, including the soft deleted ones
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::find().build(DbBackend::Postgres).to_string(),
+    ///     r#"SELECT "recipe"."id", "recipe"."name", "recipe"."cake_id", "recipe"."updated_at", "recipe"."deleted_at" FROM "recipe" WHERE "recipe"."deleted_at" IS NULL"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::with_trashed()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id", "recipe"."name", "recipe"."cake_id", "recipe"."updated_at", "recipe"."deleted_at" FROM "recipe""#
+    /// );
+    /// ```
+    fn with_trashed() -> Select<Self> {
+        default_scope::<Self, _>(Self::unscoped())
+    }
+
+    /// Find the soft deleted models only, none if the Entity is not soft deleting
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::{cake, recipe}, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     recipe::Entity::only_trashed()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "recipe"."id", "recipe"."name", "recipe"."cake_id", "recipe"."updated_at", "recipe"."deleted_at" FROM "recipe" WHERE "recipe"."deleted_at" IS NOT NULL"#
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::only_trashed()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE FALSE"#
+    /// );
+    /// ```
+    fn only_trashed() -> Select<Self> {
+        match Self::soft_delete_column() {
+            Some(col) => Self::with_trashed().filter(col.is_not_null()),
+            None => Self::with_trashed().filter(Expr::val(false).into_simple_expr()),
+        }
+    }
+
     /// Find a model by primary key
     ///
     /// # Example
@@ -257,20 +388,7 @@ pub trait EntityTrait: EntityName {
     /// # }
     /// ```
     fn find_by_id(values: <Self::PrimaryKey as PrimaryKeyTrait>::ValueType) -> Select<Self> {
-        let mut select = Self::find();
-        let mut keys = Self::PrimaryKey::iter();
-        for v in values.into_value_tuple() {
-            if let Some(key) = keys.next() {
-                let col = key.into_column();
-                select = select.filter(col.eq(v));
-            } else {
-                panic!("primary key arity mismatch");
-            }
-        }
-        if keys.next().is_some() {
-            panic!("primary key arity mismatch");
-        }
-        select
+        filter_by_id(Self::find(), values)
     }
 
     /// Insert an model into database
@@ -585,7 +703,7 @@ pub trait EntityTrait: EntityName {
     where
         A: ActiveModelTrait<Entity = Self>,
     {
-        Update::one(model)
+        default_scope::<Self, _>(Update::one(model))
     }
 
     /// Update many models in database
@@ -638,7 +756,79 @@ pub trait EntityTrait: EntityName {
     /// # }
     /// ```
     fn update_many() -> UpdateMany<Self> {
-        Update::many(Self::default())
+        default_scope::<Self, _>(Update::many(Self::default()))
+    }
+
+    /// Update many models in database, each with its own values
+    ///
+    /// - Only the columns that are `Set` are updated, see [`Update::batch`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::MySql)
+    /// #     .append_exec_results(vec![
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 2,
+    /// #         },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let update_result = fruit::Entity::update_batch(vec![
+    ///     fruit::ActiveModel {
+    ///         id: Unchanged(1),
+    ///         name: Set("Apple".to_owned()),
+    ///         ..Default::default()
+    ///     },
+    ///     fruit::ActiveModel {
+    ///         id: Unchanged(2),
+    ///         name: Set("Orange".to_owned()),
+    ///         ..Default::default()
+    ///     },
+    /// ])
+    /// .exec(&db)
+    /// .await?;
+    ///
+    /// assert_eq!(update_result.rows_affected, 2);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::MySql,
+    ///         r#"UPDATE `fruit` SET `name` = (CASE WHEN (`fruit`.`id` = ?) THEN ? WHEN (`fruit`.`id` = ?) THEN ? ELSE `fruit`.`name` END) WHERE `fruit`.`id` IN (?, ?)"#,
+    ///         vec![
+    ///             1i32.into(),
+    ///             "Apple".into(),
+    ///             2i32.into(),
+    ///             "Orange".into(),
+    ///             1i32.into(),
+    ///             2i32.into()
+    ///         ]
+    ///     )]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn update_batch<A, I>(models: I) -> UpdateBatch<A>
+    where
+        A: ActiveModelTrait<Entity = Self>,
+        I: IntoIterator<Item = A>,
+    {
+        let batch = Update::batch(models);
+        match Self::default_scope() {
+            Some(condition) => batch.filter(condition),
+            None => batch,
+        }
     }
 
     /// Delete an model from database
@@ -690,7 +880,7 @@ pub trait EntityTrait: EntityName {
     where
         A: ActiveModelTrait<Entity = Self>,
     {
-        Delete::one(model)
+        default_scope::<Self, _>(Delete::one(model))
     }
 
     /// Delete many models from database
@@ -743,7 +933,70 @@ pub trait EntityTrait: EntityName {
     /// # }
     /// ```
     fn delete_many() -> DeleteMany<Self> {
-        Delete::many(Self::default())
+        default_scope::<Self, _>(Delete::many(Self::default()))
+    }
+
+    /// Delete a model from database, even if the Entity is soft deleting
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_exec_results(vec![
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 1,
+    /// #         },
+    /// #         MockExecResult {
+    /// #             last_insert_id: 0,
+    /// #             rows_affected: 1,
+    /// #         },
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::recipe};
+    ///
+    /// let pancake = recipe::ActiveModel {
+    ///     id: Unchanged(1),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// recipe::Entity::delete(pancake.clone()).exec(&db).await?;
+    /// recipe::Entity::force_delete(pancake).exec(&db).await?;
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"UPDATE "recipe" SET "deleted_at" = CURRENT_TIMESTAMP, "updated_at" = CURRENT_TIMESTAMP WHERE "recipe"."id" = $1 AND "recipe"."deleted_at" IS NULL"#,
+    ///             vec![1i32.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             r#"DELETE FROM "recipe" WHERE "recipe"."id" = $1"#,
+    ///             vec![1i32.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn force_delete<A>(model: A) -> DeleteOne<A>
+    where
+        A: ActiveModelTrait<Entity = Self>,
+    {
+        default_scope::<Self, _>(Delete::one(model).force())
+    }
+
+    /// Delete many models from database, even if the Entity is soft deleting
+    fn force_delete_many() -> DeleteMany<Self> {
+        default_scope::<Self, _>(Delete::many(Self::default()).force())
     }
 
     /// Delete a model based on primary key
@@ -837,6 +1090,51 @@ pub trait EntityTrait: EntityName {
     }
 }
 
+/// Find a model by its primary key, ignoring the default scope and including the soft deleted
+/// ones, as when reading back a row just written
+pub(crate) fn find_by_id_unscoped<E>(
+    values: <E::PrimaryKey as PrimaryKeyTrait>::ValueType,
+) -> Select<E>
+where
+    E: EntityTrait,
+{
+    filter_by_id(E::unscoped(), values)
+}
+
+fn filter_by_id<E>(
+    mut select: Select<E>,
+    values: <E::PrimaryKey as PrimaryKeyTrait>::ValueType,
+) -> Select<E>
+where
+    E: EntityTrait,
+{
+    let mut keys = E::PrimaryKey::iter();
+    for v in values.into_value_tuple() {
+        if let Some(key) = keys.next() {
+            let col = key.into_column();
+            select = select.filter(col.eq(v));
+        } else {
+            panic!("primary key arity mismatch");
+        }
+    }
+    if keys.next().is_some() {
+        panic!("primary key arity mismatch");
+    }
+    select
+}
+
+/// Apply the default scope of the Entity to a query
+pub(crate) fn default_scope<E, Q>(query: Q) -> Q
+where
+    E: EntityTrait,
+    Q: QueryFilter,
+{
+    match E::default_scope() {
+        Some(condition) => query.filter(condition),
+        None => query,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
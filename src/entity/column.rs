@@ -338,6 +338,71 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     fn into_expr(self) -> Expr {
         Expr::expr(self.into_simple_expr())
     }
+
+    /// The current time as a value of this column, if the column is filled automatically
+    /// as marked by `#[sea_orm(created_at)]` or `#[sea_orm(updated_at)]`
+    fn current_timestamp(&self) -> Option<Value> {
+        None
+    }
+}
+
+/// Date time types which can be filled with the current time,
+/// used by `#[sea_orm(created_at)]` and `#[sea_orm(updated_at)]` columns
+pub trait CurrentTimestamp {
+    /// The current time
+    fn current_timestamp() -> Self;
+}
+
+impl<T> CurrentTimestamp for Option<T>
+where
+    T: CurrentTimestamp,
+{
+    fn current_timestamp() -> Self {
+        Some(T::current_timestamp())
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::NaiveDateTime {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::Utc> {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::Local> {
+    fn current_timestamp() -> Self {
+        chrono::Local::now()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl CurrentTimestamp for chrono::DateTime<chrono::FixedOffset> {
+    fn current_timestamp() -> Self {
+        chrono::Utc::now().into()
+    }
+}
+
+#[cfg(feature = "with-time")]
+impl CurrentTimestamp for time::PrimitiveDateTime {
+    fn current_timestamp() -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        time::PrimitiveDateTime::new(now.date(), now.time())
+    }
+}
+
+#[cfg(feature = "with-time")]
+impl CurrentTimestamp for time::OffsetDateTime {
+    fn current_timestamp() -> Self {
+        time::OffsetDateTime::now_utc()
+    }
 }
 
 impl ColumnType {
@@ -19,7 +19,7 @@ pub trait Linked {
 
     /// Find all the Entities that are linked to the Entity
     fn find_linked(&self) -> Select<Self::ToEntity> {
-        let mut select = Select::new();
+        let mut select = Self::ToEntity::find();
         for (i, mut rel) in self.link().into_iter().rev().enumerate() {
             let from_tbl = Alias::new(&format!("r{}", i)).into_iden();
             let to_tbl = if i > 0 {
@@ -1,7 +1,7 @@
 use crate::{
     ActiveModelBehavior, ActiveModelTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
-    IntoActiveModel, Linked, QueryFilter, QueryResult, Related, Select, SelectModel, SelectorRaw,
-    Statement,
+    IntoActiveModel, Linked, QueryFilter, QueryResult, Related, Select, SelectModel, SelectWith,
+    SelectorRaw, Statement,
 };
 use async_trait::async_trait;
 pub use sea_query::Value;
@@ -37,6 +37,16 @@ pub trait ModelTrait: Clone + Send + Debug {
         l.find_linked().belongs_to_tbl_alias(self, tbl_alias)
     }
 
+    /// Find all Models reachable from this Model by following a self-referencing link recursively
+    fn find_linked_recursive<L>(&self, l: L) -> SelectWith<Self::Entity>
+    where
+        L: Linked<FromEntity = Self::Entity, ToEntity = Self::Entity>,
+    {
+        Self::Entity::find()
+            .belongs_to(self)
+            .find_linked_recursive(l)
+    }
+
     /// Delete an model
     async fn delete<'a, A, C>(self, db: &'a C) -> Result<DeleteResult, DbErr>
     where
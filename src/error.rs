@@ -40,6 +40,9 @@ pub enum DbErr {
     /// The record was not found in the database
     #[error("RecordNotFound Error: {0}")]
     RecordNotFound(String),
+    /// The record has been modified since it was read, as detected by its version column
+    #[error("StaleRecord Error: {0}")]
+    StaleRecord(String),
     /// Thrown by `TryFrom<ActiveModel>`, which assumes all attributes are set/unchanged
     #[error("Attribute {0} is NotSet")]
     AttrNotSet(String),
@@ -55,6 +58,14 @@ pub enum DbErr {
     /// A migration error
     #[error("Migration Error: {0}")]
     Migration(String),
+    /// The operation is not supported by the database backend
+    #[error("{ctx} is not supported by {db} backend")]
+    BackendNotSupported {
+        /// Database backend
+        db: &'static str,
+        /// Context
+        ctx: &'static str,
+    },
 }
 
 /// Runtime error
@@ -69,6 +80,32 @@ pub enum RuntimeErr {
     Internal(String),
 }
 
+impl DbErr {
+    /// Check if the error aborted a transaction which is expected to succeed when run again,
+    /// namely a serialization failure (SQLSTATE `40001`, also raised on MySQL deadlocks)
+    /// or a Postgres deadlock (SQLSTATE `40P01`)
+    pub fn is_transaction_retryable(&self) -> bool {
+        match self {
+            DbErr::Conn(err) | DbErr::Exec(err) | DbErr::Query(err) => {
+                err.is_transaction_retryable()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl RuntimeErr {
+    fn is_transaction_retryable(&self) -> bool {
+        match self {
+            #[cfg(feature = "sqlx-dep")]
+            RuntimeErr::SqlxError(SqlxError::Database(err)) => {
+                matches!(err.code().as_deref(), Some("40001" | "40P01"))
+            }
+            _ => false,
+        }
+    }
+}
+
 impl PartialEq for DbErr {
     fn eq(&self, other: &Self) -> bool {
         self.to_string() == other.to_string()
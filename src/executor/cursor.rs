@@ -1,6 +1,7 @@
 use crate::{
-    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IntoIdentity, QueryOrder,
-    Select, SelectModel, SelectorTrait,
+    build_select, no_schema_qualifier, no_tenant_filter, no_tenant_join, qualify_schema,
+    tenant_filter, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IntoIdentity,
+    QueryOrder, SchemaQualifier, Select, SelectModel, SelectorTrait, TenantFilter,
 };
 use sea_query::{
     Condition, DynIden, Expr, IntoValueTuple, Order, OrderedStatement, SeaRc, SelectStatement,
@@ -21,6 +22,8 @@ where
     pub(crate) table: DynIden,
     pub(crate) order_columns: Identity,
     pub(crate) last: bool,
+    pub(crate) tenant_filter: TenantFilter,
+    pub(crate) schema_qualifier: SchemaQualifier,
     pub(crate) phantom: PhantomData<S>,
 }
 
@@ -38,6 +41,8 @@ where
             table,
             order_columns: order_columns.into_identity(),
             last: false,
+            tenant_filter: no_tenant_filter,
+            schema_qualifier: no_schema_qualifier,
             phantom: PhantomData,
         }
     }
@@ -145,7 +150,11 @@ where
     where
         C: ConnectionTrait,
     {
-        let stmt = db.get_database_backend().build(&self.query);
+        let mut query = self.query.clone();
+        if let Some(condition) = db.tenant().and_then(self.tenant_filter) {
+            query.cond_where(condition);
+        }
+        let stmt = build_select(&query, self.schema_qualifier, no_tenant_join, db);
         let rows = db.query_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
@@ -167,6 +176,8 @@ where
             table: self.table,
             order_columns: self.order_columns,
             last: self.last,
+            tenant_filter: self.tenant_filter,
+            schema_qualifier: self.schema_qualifier,
             phantom: PhantomData,
         }
     }
@@ -179,6 +190,8 @@ where
             table: self.table,
             order_columns: self.order_columns,
             last: self.last,
+            tenant_filter: self.tenant_filter,
+            schema_qualifier: self.schema_qualifier,
             phantom: PhantomData,
         }
     }
@@ -217,7 +230,11 @@ where
     where
         C: IntoIdentity,
     {
-        Cursor::new(self.query, SeaRc::new(E::default()), order_columns)
+        Cursor {
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema::<E>,
+            ..Cursor::new(self.query, SeaRc::new(E::default()), order_columns)
+        }
     }
 }
 
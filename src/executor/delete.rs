@@ -1,7 +1,9 @@
 use crate::{
-    error::*, ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, Statement,
+    cast_enum_as_text, entity_table_ref, error::*, tenant_filter, ActiveModelTrait, ColumnTrait,
+    ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, Iterable, SelectModel, SelectorRaw,
+    Statement,
 };
-use sea_query::DeleteStatement;
+use sea_query::{Condition, DeleteStatement, Expr, Query, TableRef, UpdateStatement};
 use std::future::Future;
 
 /// Handles DELETE operations in a ActiveModel using [DeleteStatement]
@@ -27,7 +29,7 @@ where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_only::<A::Entity, _>(self.query, self.condition, self.force, db)
     }
 }
 
@@ -41,7 +43,68 @@ where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_only::<E, _>(self.query, self.condition, self.force, db)
+    }
+
+    /// Execute a DELETE operation on many ActiveModels and return the deleted models (use `RETURNING` syntax)
+    ///
+    /// Returns [`DbErr::BackendNotSupported`] if the database doesn't support `RETURNING`,
+    /// which is the case for MySQL and MariaDB when the Entity is soft deleting
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![fruit::Model {
+    /// #         id: 3,
+    /// #         name: "Apple".to_owned(),
+    /// #         cake_id: None,
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let deleted = fruit::Entity::delete_many()
+    ///     .filter(fruit::Column::Name.contains("Apple"))
+    ///     .exec_with_returning(&db)
+    ///     .await?;
+    ///
+    /// assert_eq!(deleted.len(), 1);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"DELETE FROM "fruit" WHERE "fruit"."name" LIKE $1 RETURNING "id", "name", "cake_id""#,
+    ///         vec!["%Apple%".into()]
+    ///     )]
+    /// );
+    ///
+    /// let db = MockDatabase::new(DbBackend::MySql).into_connection();
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::delete_many().exec_with_returning(&db).await,
+    ///     Err(DbErr::BackendNotSupported {
+    ///         db: "MySQL",
+    ///         ctx: "RETURNING",
+    ///     })
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<E::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+    {
+        exec_delete_with_returning::<E, _>(self.query, self.condition, self.force, db)
     }
 }
 
@@ -61,11 +124,106 @@ impl Deleter {
     }
 }
 
-async fn exec_delete_only<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete_only<E, C>(
+    query: DeleteStatement,
+    condition: Condition,
+    force: bool,
+    db: &C,
+) -> Result<DeleteResult, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let table_ref = entity_table_ref::<E, _>(db);
+    match E::soft_delete_column() {
+        Some(col) if !force => {
+            let query = soft_delete::<E, _>(condition, table_ref, col, db);
+            exec_delete(db.get_database_backend().build(&query), db).await
+        }
+        _ => {
+            Deleter::new(bind::<E, _>(query, table_ref, db))
+                .exec(db)
+                .await
+        }
+    }
+}
+
+async fn exec_delete_with_returning<E, C>(
+    query: DeleteStatement,
+    condition: Condition,
+    force: bool,
+    db: &C,
+) -> Result<Vec<E::Model>, DbErr>
 where
+    E: EntityTrait,
     C: ConnectionTrait,
 {
-    Deleter::new(query).exec(db).await
+    let table_ref = entity_table_ref::<E, _>(db);
+    let db_backend = db.get_database_backend();
+    let soft_delete_column = E::soft_delete_column().filter(|_| !force);
+    let support_returning = match soft_delete_column {
+        Some(_) => db.support_update_returning(),
+        None => db.support_returning(),
+    };
+    if !support_returning {
+        return Err(DbErr::BackendNotSupported {
+            db: db_backend.as_str(),
+            ctx: "RETURNING",
+        });
+    }
+    let returning =
+        Query::returning().exprs(E::Column::iter().map(|c| cast_enum_as_text(Expr::col(c), &c)));
+    let statement = match soft_delete_column {
+        Some(col) => db_backend
+            .build(soft_delete::<E, _>(condition, table_ref, col, db).returning(returning)),
+        None => Statement::build_with_returning(
+            db_backend,
+            bind::<E, _>(query, table_ref, db).returning(returning),
+        ),
+    };
+    SelectorRaw::<SelectModel<E::Model>>::from_statement(statement)
+        .all(db)
+        .await
+}
+
+/// Prepare the delete for the connection, restricting it to the rows of the tenant
+/// bound to the connection and moving the table into the schema bound to it
+fn bind<E, C>(mut query: DeleteStatement, table_ref: TableRef, db: &C) -> DeleteStatement
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    if let Some(condition) = db.tenant().and_then(tenant_filter::<E>) {
+        query.cond_where(condition);
+    }
+    query.from_table(table_ref);
+    query
+}
+
+/// Build the UPDATE of a soft delete from the conditions of the DELETE, setting the soft delete
+/// column, and the `updated_at` column if any, to the current timestamp and skipping the rows
+/// which are already soft deleted
+fn soft_delete<E, C>(
+    mut condition: Condition,
+    table_ref: TableRef,
+    col: E::Column,
+    db: &C,
+) -> UpdateStatement
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    if let Some(tenant) = db.tenant().and_then(tenant_filter::<E>) {
+        condition = condition.add(tenant);
+    }
+    let mut query = Query::update();
+    query
+        .table(table_ref)
+        .value(col, Expr::cust("CURRENT_TIMESTAMP"));
+    if let Some(updated_at) = E::updated_at_column() {
+        query.value(updated_at, Expr::cust("CURRENT_TIMESTAMP"));
+    }
+    query.cond_where(condition.add(col.is_null())).to_owned()
 }
 
 async fn exec_delete<C>(statement: Statement, db: &C) -> Result<DeleteResult, DbErr>
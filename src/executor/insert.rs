@@ -1,8 +1,12 @@
 use crate::{
-    cast_enum_as_text, error::*, ActiveModelTrait, ConnectionTrait, EntityTrait, Insert,
-    IntoActiveModel, Iterable, PrimaryKeyTrait, SelectModel, SelectorRaw, Statement, TryFromU64,
+    cast_enum_as_text, error::*, find_by_id_unscoped, ActiveModelTrait, ColumnTrait,
+    ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Insert, IntoActiveModel, Iterable,
+    ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder, SelectModel,
+    SelectorRaw, Statement, TryFromU64,
+};
+use sea_query::{
+    Condition, Expr, FromValueTuple, Iden, InsertStatement, IntoColumnRef, Query, ValueTuple,
 };
-use sea_query::{Expr, FromValueTuple, Iden, InsertStatement, IntoColumnRef, Query, ValueTuple};
 use std::{future::Future, marker::PhantomData};
 
 /// Defines a structure to perform INSERT operations in an ActiveModel
@@ -37,15 +41,16 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
+        let insert = self.bind(db);
         // so that self is dropped before entering await
-        let mut query = self.query;
+        let mut query = insert.query;
         if db.support_returning() && <A::Entity as EntityTrait>::PrimaryKey::iter().count() > 0 {
             let returning = Query::returning().columns(
                 <A::Entity as EntityTrait>::PrimaryKey::iter().map(|c| c.into_column_ref()),
             );
             query.returning(returning);
         }
-        Inserter::<A>::new(self.primary_key, query).exec(db)
+        Inserter::<A>::new(insert.primary_key, query).exec(db)
     }
 
     /// Execute an insert operation without returning (don't use `RETURNING` syntax)
@@ -59,7 +64,8 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db)
+        let insert = self.bind(db);
+        Inserter::<A>::new(insert.primary_key, insert.query).exec_without_returning(db)
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if database supported)
@@ -72,7 +78,122 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
+        let insert = self.bind(db);
+        Inserter::<A>::new(insert.primary_key, insert.query).exec_with_returning(db)
+    }
+
+    /// Execute an upsert on Postgres and return the upserted model, along with whether
+    /// the row was inserted as told by its `xmax` being zero
+    pub(crate) async fn exec_upsert_with_returning<C>(
+        self,
+        db: &C,
+    ) -> Result<(<A::Entity as EntityTrait>::Model, bool), DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = self.bind(db).query;
+        let returning = Query::returning().exprs(
+            <A::Entity as EntityTrait>::Column::iter()
+                .map(|c| cast_enum_as_text(Expr::col(c), &c))
+                .chain([Expr::cust("(xmax = 0) AS upsert_inserted")]),
+        );
+        query.returning(returning);
+        match db
+            .query_one(db.get_database_backend().build(&query))
+            .await?
+        {
+            Some(row) => Ok((
+                <A::Entity as EntityTrait>::Model::from_query_result(&row, "")?,
+                row.try_get("", "upsert_inserted")?,
+            )),
+            None => Err(DbErr::RecordNotFound(
+                "Failed to find upserted item".to_owned(),
+            )),
+        }
+    }
+
+    /// Execute an insert operation and return all inserted models, in insertion order
+    ///
+    /// On databases supporting `RETURNING` the rows are returned by the insert statement itself.
+    /// Otherwise the rows are selected again after the insert, either by the primary keys of the
+    /// inserted models (if every model has its primary key set) or, for a single auto-increment
+    /// primary key, by the contiguous range of ids starting at `LAST_INSERT_ID()`.
+    /// The latter assumes the ids of a multi-row insert are consecutive, which holds for MySQL
+    /// with `auto_increment_increment = 1` and an `innodb_autoinc_lock_mode` other than 2.
+    /// Models mixing set and generated primary keys are rejected before inserting, and
+    /// [`DbErr::RecordNotFound`] is returned if not every inserted row is found.
+    ///
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::MySql)
+    /// #     .append_exec_results(vec![MockExecResult {
+    /// #         last_insert_id: 11,
+    /// #         rows_affected: 2,
+    /// #     }])
+    /// #     .append_query_results(vec![vec![
+    /// #         cake::Model {
+    /// #             id: 11,
+    /// #             name: "Apple Pie".to_owned(),
+    /// #         },
+    /// #         cake::Model {
+    /// #             id: 12,
+    /// #             name: "Orange Scone".to_owned(),
+    /// #         },
+    /// #     ]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let cakes = cake::Entity::insert_many(vec![
+    ///     cake::ActiveModel {
+    ///         name: Set("Apple Pie".to_owned()),
+    ///         ..Default::default()
+    ///     },
+    ///     cake::ActiveModel {
+    ///         name: Set("Orange Scone".to_owned()),
+    ///         ..Default::default()
+    ///     },
+    /// ])
+    /// .exec_with_returning_many(&db)
+    /// .await?;
+    ///
+    /// assert_eq!(cakes.iter().map(|c| c.id).collect::<Vec<_>>(), vec![11, 12]);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"INSERT INTO `cake` (`name`) VALUES (?), (?)"#,
+    ///             vec!["Apple Pie".into(), "Orange Scone".into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::MySql,
+    ///             r#"SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` BETWEEN ? AND ? ORDER BY `cake`.`id` ASC"#,
+    ///             vec![11u64.into(), 12u64.into()]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exec_with_returning_many<'a, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>> + 'a
+    where
+        <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let insert = self.bind(db);
+        exec_insert_with_returning_many::<A, _>(insert.primary_keys, insert.query, db)
     }
 }
 
@@ -96,7 +217,11 @@ where
         A: 'a,
     {
         let builder = db.get_database_backend();
-        exec_insert(self.primary_key, builder.build(&self.query), db)
+        let statement = match db.support_returning() {
+            true => Statement::build_with_returning(builder, &self.query),
+            false => builder.build(&self.query),
+        };
+        exec_insert(self.primary_key, statement, db)
     }
 
     /// Execute an insert operation
@@ -142,8 +267,10 @@ where
             let cols = PrimaryKey::<A>::iter()
                 .map(|col| col.to_string())
                 .collect::<Vec<_>>();
-            let res = db.query_one(statement).await?.unwrap();
-            res.try_get_many("", cols.as_ref()).ok()
+            match db.query_one(statement).await? {
+                Some(res) => res.try_get_many("", cols.as_ref()).ok(),
+                None => None,
+            }
         }
         false => {
             let last_insert_id = db.execute(statement).await?.last_insert_id();
@@ -191,7 +318,7 @@ where
             );
             insert_statement.returning(returning);
             SelectorRaw::<SelectModel<<A::Entity as EntityTrait>::Model>>::from_statement(
-                db_backend.build(&insert_statement),
+                Statement::build_with_returning(db_backend, &insert_statement),
             )
             .one(db)
             .await?
@@ -199,7 +326,7 @@ where
         false => {
             let insert_res =
                 exec_insert::<A, _>(primary_key, db_backend.build(&insert_statement), db).await?;
-            <A::Entity as EntityTrait>::find_by_id(insert_res.last_insert_id)
+            find_by_id_unscoped::<A::Entity>(insert_res.last_insert_id)
                 .one(db)
                 .await?
         }
@@ -211,3 +338,244 @@ where
         )),
     }
 }
+
+async fn exec_insert_with_returning_many<A, C>(
+    primary_keys: Vec<Option<ValueTuple>>,
+    mut insert_statement: InsertStatement,
+    db: &C,
+) -> Result<Vec<<A::Entity as EntityTrait>::Model>, DbErr>
+where
+    <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
+    C: ConnectionTrait,
+    A: ActiveModelTrait,
+{
+    // nothing was added, don't insert a row of default values
+    if primary_keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let db_backend = db.get_database_backend();
+    if db.support_returning() {
+        let returning = Query::returning().exprs(
+            <A::Entity as EntityTrait>::Column::iter().map(|c| cast_enum_as_text(Expr::col(c), &c)),
+        );
+        insert_statement.returning(returning);
+        return SelectorRaw::<SelectModel<<A::Entity as EntityTrait>::Model>>::from_statement(
+            Statement::build_with_returning(db_backend, &insert_statement),
+        )
+        .all(db)
+        .await;
+    }
+    // the rows are looked up by the primary keys set, or else by the range of generated ids,
+    // which cannot tell the rows with a primary key set apart
+    let set_keys = primary_keys.iter().filter(|key| key.is_some()).count();
+    if set_keys == primary_keys.len() {
+        let primary_keys: Vec<_> = primary_keys.into_iter().flatten().collect();
+        db.execute(db_backend.build(&insert_statement)).await?;
+        let mut condition = Condition::any();
+        for primary_key in primary_keys.iter() {
+            let mut key_condition = Condition::all();
+            for (key, value) in
+                <A::Entity as EntityTrait>::PrimaryKey::iter().zip(primary_key.clone())
+            {
+                key_condition = key_condition.add(key.into_column().eq(value));
+            }
+            condition = condition.add(key_condition);
+        }
+        let mut found = <A::Entity as EntityTrait>::unscoped()
+            .filter(condition)
+            .all(db)
+            .await?;
+        let mut models = Vec::with_capacity(found.len());
+        for primary_key in primary_keys.iter() {
+            let values: Vec<_> = primary_key.clone().into_iter().collect();
+            let pos = found.iter().position(|model| {
+                <A::Entity as EntityTrait>::PrimaryKey::iter()
+                    .map(|key| model.get(key.into_column()))
+                    .eq(values.iter().cloned())
+            });
+            if let Some(pos) = pos {
+                models.push(found.swap_remove(pos));
+            }
+        }
+        return found_inserted(models, primary_keys.len() as u64);
+    }
+    if set_keys > 0 {
+        return Err(DbErr::Custom(
+            "Cannot find the inserted items mixing set and generated primary keys without RETURNING"
+                .to_owned(),
+        ));
+    }
+    let mut keys = <A::Entity as EntityTrait>::PrimaryKey::iter();
+    let key = match (keys.next(), keys.next()) {
+        (Some(key), None)
+            if <<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() =>
+        {
+            key.into_column()
+        }
+        _ => return Err(DbErr::UnpackInsertId),
+    };
+    let exec_result = db.execute(db_backend.build(&insert_statement)).await?;
+    let rows_affected = exec_result.rows_affected();
+    if rows_affected == 0 {
+        return Ok(Vec::new());
+    }
+    let last_insert_id = exec_result.last_insert_id();
+    // MySQL reports the first id generated by a multi-row insert, SQLite the last one
+    let first_id = match db_backend {
+        DbBackend::Sqlite => (last_insert_id + 1)
+            .checked_sub(rows_affected)
+            .ok_or(DbErr::UnpackInsertId)?,
+        _ => last_insert_id,
+    };
+    let models = <A::Entity as EntityTrait>::unscoped()
+        .filter(key.between(first_id, first_id + rows_affected - 1))
+        .order_by_asc(key)
+        .all(db)
+        .await?;
+    found_inserted(models, rows_affected)
+}
+
+/// Check that every inserted row is found
+fn found_inserted<M>(models: Vec<M>, inserted: u64) -> Result<Vec<M>, DbErr> {
+    if models.len() as u64 != inserted {
+        return Err(DbErr::RecordNotFound(format!(
+            "Found {} of the {} inserted items",
+            models.len(),
+            inserted
+        )));
+    }
+    Ok(models)
+}
+
+#[cfg(test)]
+#[cfg(feature = "mock")]
+mod tests {
+    use crate::{entity::prelude::*, tests_cfg::*, *};
+    use pretty_assertions::assert_eq;
+    use sea_query::ValueTuple;
+
+    #[smol_potat::test]
+    async fn insert_many_mixed_primary_keys() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+        let insert = cake::Entity::insert_many(["Apple Pie", "Orange Scone"].map(|name| {
+            cake::ActiveModel {
+                name: Set(name.to_owned()),
+                ..Default::default()
+            }
+        }));
+
+        assert_eq!(
+            super::exec_insert_with_returning_many::<cake::ActiveModel, _>(
+                vec![Some(ValueTuple::One(1.into())), None],
+                insert.into_query(),
+                &db
+            )
+            .await,
+            Err(DbErr::Custom(
+                "Cannot find the inserted items mixing set and generated primary keys without RETURNING"
+                    .to_owned()
+            ))
+        );
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn insert_many_missing_rows() -> Result<(), DbErr> {
+        let apple_pie = cake::Model {
+            id: 11,
+            name: "Apple Pie".to_owned(),
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 11,
+                    rows_affected: 2,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 2,
+                },
+            ])
+            .append_query_results(vec![vec![apple_pie.clone()], vec![apple_pie.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            cake::Entity::insert_many(["Apple Pie", "Orange Scone"].map(|name| {
+                cake::ActiveModel {
+                    name: Set(name.to_owned()),
+                    ..Default::default()
+                }
+            }))
+            .exec_with_returning_many(&db)
+            .await,
+            Err(DbErr::RecordNotFound(
+                "Found 1 of the 2 inserted items".to_owned()
+            ))
+        );
+        assert_eq!(
+            cake::Entity::insert_many([(11, "Apple Pie"), (12, "Orange Scone")].map(
+                |(id, name)| cake::ActiveModel {
+                    id: Set(id),
+                    name: Set(name.to_owned()),
+                }
+            ))
+            .exec_with_returning_many(&db)
+            .await,
+            Err(DbErr::RecordNotFound(
+                "Found 1 of the 2 inserted items".to_owned()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    #[cfg(feature = "with-chrono")]
+    async fn insert_soft_deleted() -> Result<(), DbErr> {
+        let deleted_at = "2022-01-07T12:11:23+00:00".parse().unwrap();
+        let pancake = recipe::Model {
+            id: 1,
+            name: "Pancake".to_owned(),
+            cake_id: None,
+            updated_at: Some(deleted_at),
+            deleted_at: Some(deleted_at),
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 1,
+                rows_affected: 1,
+            }])
+            .append_query_results(vec![vec![pancake.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            recipe::ActiveModel {
+                name: Set("Pancake".to_owned()),
+                updated_at: Set(Some(deleted_at)),
+                deleted_at: Set(Some(deleted_at)),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await?,
+            pancake
+        );
+
+        assert_eq!(
+            db.into_transaction_log()[1],
+            Transaction::from_sql_and_values(
+                DbBackend::MySql,
+                [
+                    "SELECT `recipe`.`id`, `recipe`.`name`, `recipe`.`cake_id`, `recipe`.`updated_at`, `recipe`.`deleted_at`",
+                    "FROM `recipe` WHERE `recipe`.`id` = ? LIMIT ?",
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), 1u64.into()]
+            )
+        );
+
+        Ok(())
+    }
+}
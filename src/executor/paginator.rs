@@ -1,10 +1,11 @@
 use crate::{
-    error::*, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult, Select, SelectModel,
-    SelectTwo, SelectTwoModel, Selector, SelectorRaw, SelectorTrait,
+    build_select_with, error::*, no_schema_qualifier, no_tenant_join, ConnectionTrait, DbBackend,
+    EntityTrait, FromQueryResult, SchemaQualifier, Select, SelectModel, SelectTwo, SelectTwoModel,
+    SelectWith, Selector, SelectorRaw, SelectorTrait, TenantJoin,
 };
 use async_stream::stream;
 use futures::Stream;
-use sea_query::{Alias, Expr, SelectStatement};
+use sea_query::{Alias, Expr, SelectStatement, WithClause};
 use std::{marker::PhantomData, pin::Pin};
 
 /// Pin a Model so that stream operations can be performed on the model
@@ -22,6 +23,9 @@ where
     pub(crate) page_size: u64,
     pub(crate) db: &'db C,
     pub(crate) selector: PhantomData<S>,
+    pub(crate) schema_qualifier: SchemaQualifier,
+    pub(crate) with: Option<WithClause>,
+    pub(crate) tenant_join: TenantJoin,
 }
 
 /// Define a structure containing the numbers of items and pages of a Paginator
@@ -48,8 +52,13 @@ where
             .limit(self.page_size)
             .offset(self.page_size * page)
             .to_owned();
-        let builder = self.db.get_database_backend();
-        let stmt = builder.build(&query);
+        let stmt = build_select_with(
+            &self.with,
+            &query,
+            self.schema_qualifier,
+            self.tenant_join,
+            self.db,
+        );
         let rows = self.db.query_all(stmt).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
@@ -67,13 +76,17 @@ where
     /// Get the total number of items
     pub async fn num_items(&self) -> Result<u64, DbErr> {
         let builder = self.db.get_database_backend();
-        let stmt = builder.build(
+        let stmt = build_select_with(
+            &self.with,
             SelectStatement::new()
                 .expr(Expr::cust("COUNT(*) AS num_items"))
                 .from_subquery(
                     self.query.clone().reset_limit().reset_offset().to_owned(),
                     Alias::new("sub_query"),
                 ),
+            self.schema_qualifier,
+            self.tenant_join,
+            self.db,
         );
         let result = match self.db.query_one(stmt).await? {
             Some(res) => res,
@@ -235,12 +248,22 @@ where
 
     fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, S> {
         assert!(page_size != 0, "page_size should not be zero");
+        let Selector {
+            query,
+            schema_qualifier,
+            with,
+            tenant_join,
+            ..
+        } = self.filter_tenant(db);
         Paginator {
-            query: self.query,
+            query,
             page: 0,
             page_size,
             db,
             selector: PhantomData,
+            schema_qualifier,
+            with,
+            tenant_join,
         }
     }
 }
@@ -267,6 +290,9 @@ where
             page_size,
             db,
             selector: PhantomData,
+            schema_qualifier: no_schema_qualifier,
+            with: None,
+            tenant_join: no_tenant_join,
         }
     }
 }
@@ -284,6 +310,19 @@ where
     }
 }
 
+impl<'db, C, M, E> PaginatorTrait<'db, C> for SelectWith<E>
+where
+    C: ConnectionTrait,
+    E: EntityTrait<Model = M>,
+    M: FromQueryResult + Sized + Send + Sync + 'db,
+{
+    type Selector = SelectModel<M>;
+
+    fn paginate(self, db: &'db C, page_size: u64) -> Paginator<'db, C, Self::Selector> {
+        self.into_model().paginate(db, page_size)
+    }
+}
+
 impl<'db, C, M, N, E, F> PaginatorTrait<'db, C> for SelectTwo<E, F>
 where
     C: ConnectionTrait,
@@ -178,6 +178,47 @@ macro_rules! try_getable_unsigned {
     };
 }
 
+macro_rules! try_getable_float {
+    ( $type: ty ) => {
+        impl TryGetable for $type {
+            fn try_get(res: &QueryResult, pre: &str, col: &str) -> Result<Self, TryGetError> {
+                #[allow(unused_variables)]
+                let column = format!("{}{}", pre, col);
+                match &res.row {
+                    #[cfg(feature = "sqlx-mysql")]
+                    QueryResultRow::SqlxMySql(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .and_then(|opt| opt.ok_or(TryGetError::Null(column)))
+                    }
+                    #[cfg(feature = "sqlx-postgres")]
+                    QueryResultRow::SqlxPostgres(row) => {
+                        use sqlx::Row;
+                        row.try_get::<Option<$type>, _>(column.as_str())
+                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .and_then(|opt| opt.ok_or(TryGetError::Null(column)))
+                    }
+                    #[cfg(feature = "sqlx-sqlite")]
+                    QueryResultRow::SqlxSqlite(row) => {
+                        sqlite_try_get_real::<$type>(row, column.as_str())
+                            .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))
+                            .and_then(|opt| opt.ok_or(TryGetError::Null(column)))
+                    }
+                    #[cfg(feature = "mock")]
+                    #[allow(unused_variables)]
+                    QueryResultRow::Mock(row) => row.try_get(column.as_str()).map_err(|e| {
+                        debug_print!("{:#?}", e.to_string());
+                        TryGetError::Null(column)
+                    }),
+                    #[allow(unreachable_patterns)]
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+
 macro_rules! try_getable_mysql {
     ( $type: ty ) => {
         impl TryGetable for $type {
@@ -269,8 +310,8 @@ try_getable_all!(i64);
 try_getable_unsigned!(u8);
 try_getable_unsigned!(u16);
 try_getable_mysql!(u64);
-try_getable_all!(f32);
-try_getable_all!(f64);
+try_getable_float!(f32);
+try_getable_float!(f64);
 try_getable_all!(String);
 try_getable_all!(Vec<u8>);
 
@@ -333,8 +374,7 @@ impl TryGetable for Decimal {
             #[cfg(feature = "sqlx-sqlite")]
             QueryResultRow::SqlxSqlite(row) => {
                 use sqlx::Row;
-                let val: Option<f64> = row
-                    .try_get(column.as_str())
+                let val: Option<f64> = sqlite_try_get_real(row, column.as_str())
                     .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))?;
                 match val {
                     Some(v) => Decimal::try_from(v).map_err(|e| {
@@ -385,8 +425,7 @@ impl TryGetable for BigDecimal {
             #[cfg(feature = "sqlx-sqlite")]
             QueryResultRow::SqlxSqlite(row) => {
                 use sqlx::Row;
-                let val: Option<f64> = row
-                    .try_get(column.as_str())
+                let val: Option<f64> = sqlite_try_get_real(row, column.as_str())
                     .map_err(|e| TryGetError::DbErr(crate::sqlx_error_to_query_err(e)))?;
                 match val {
                     Some(v) => BigDecimal::try_from(v).map_err(|e| {
@@ -764,6 +803,30 @@ where
     }
 }
 
+/// Get a floating point value from a SQLite row
+///
+/// Values returned by `RETURNING` carry no declared column type, so a `REAL` column holding
+/// `10.0` is reported as `INTEGER`. Only then is the value read unchecked, as SQLite converts
+/// it to a double on read.
+#[cfg(feature = "sqlx-sqlite")]
+fn sqlite_try_get_real<T>(
+    row: &sqlx::sqlite::SqliteRow,
+    column: &str,
+) -> Result<Option<T>, sqlx::Error>
+where
+    T: for<'r> sqlx::Decode<'r, sqlx::Sqlite> + sqlx::Type<sqlx::Sqlite>,
+{
+    use sqlx::{Row, TypeInfo, ValueRef};
+    match row.try_get::<Option<T>, _>(column) {
+        Err(sqlx::Error::ColumnDecode { .. })
+            if row.try_get_raw(column)?.type_info().name() == "INTEGER" =>
+        {
+            row.try_get_unchecked(column)
+        }
+        res => res,
+    }
+}
+
 fn try_get_many_with_slice_len_of(len: usize, cols: &[String]) -> Result<(), TryGetError> {
     if cols.len() < len {
         Err(TryGetError::DbErr(DbErr::Type(format!(
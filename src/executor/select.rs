@@ -1,10 +1,16 @@
 use crate::{
-    error::*, ConnectionTrait, EntityTrait, FromQueryResult, IdenStatic, Iterable, ModelTrait,
-    PrimaryKeyToColumn, QueryResult, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, Statement,
-    StreamTrait, TryGetableMany,
+    build_select_with, error::*, no_schema_qualifier, no_tenant_filter, no_tenant_join,
+    qualify_schema, qualify_schema_two, tenant_filter, tenant_join, ColumnTrait, ConnectionTrait,
+    EntityTrait, FromQueryResult, IdenStatic, IntoSimpleExpr, Iterable, ModelTrait, Paginator,
+    PrimaryKeyToColumn, QueryResult, SchemaQualifier, Select, SelectA, SelectB, SelectTwo,
+    SelectTwoMany, SelectWith, Statement, StreamTrait, TenantFilter, TenantJoin, TryGetable,
+    TryGetableMany,
 };
 use futures::{Stream, TryStreamExt};
-use sea_query::SelectStatement;
+use sea_query::{
+    Alias, ColumnRef, DynIden, Func, OrderedStatement, SeaRc, SelectStatement, SimpleExpr,
+    WithClause,
+};
 use std::marker::PhantomData;
 use std::pin::Pin;
 
@@ -19,6 +25,14 @@ where
 {
     pub(crate) query: SelectStatement,
     selector: S,
+    /// Restricts the query to the tenant bound to the connection
+    pub(crate) tenant_filter: TenantFilter,
+    /// Moves the tables of the query into the schema bound to the connection
+    pub(crate) schema_qualifier: SchemaQualifier,
+    /// The `WITH` clause preceding the query
+    pub(crate) with: Option<WithClause>,
+    /// Restricts the joined tables of the query to the tenant bound to the connection
+    pub(crate) tenant_join: TenantJoin,
 }
 
 /// Performs a raw `SELECT` operation on a model
@@ -131,6 +145,10 @@ where
         Selector {
             query: self.query,
             selector: SelectModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema::<E>,
+            with: None,
+            tenant_join: no_tenant_join,
         }
     }
 
@@ -140,6 +158,10 @@ where
         Selector {
             query: self.query,
             selector: SelectModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema::<E>,
+            with: None,
+            tenant_join: no_tenant_join,
         }
     }
 
@@ -250,7 +272,11 @@ where
         T: TryGetableMany,
         C: strum::IntoEnumIterator + sea_query::Iden,
     {
-        Selector::<SelectGetableValue<T, C>>::with_columns(self.query)
+        Selector {
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema::<E>,
+            ..Selector::<SelectGetableValue<T, C>>::with_columns(self.query)
+        }
     }
 
     /// Get one Model from the SELECT query
@@ -279,6 +305,111 @@ where
     {
         self.into_model().stream(db).await
     }
+
+    /// Check if the SELECT query matches any row
+    pub async fn exists<C>(self, db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().exists(db).await
+    }
+
+    /// Sum up the values of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn sum<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().sum(expr, db).await
+    }
+
+    /// Average the values of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn avg<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().avg(expr, db).await
+    }
+
+    /// Get the minimum value of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn min<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().min(expr, db).await
+    }
+
+    /// Get the maximum value of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn max<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model>().max(expr, db).await
+    }
+}
+
+impl<E> SelectWith<E>
+where
+    E: EntityTrait,
+{
+    /// Return a [Selector] from `Self` that wraps a [SelectModel]
+    pub fn into_model<M>(self) -> Selector<SelectModel<M>>
+    where
+        M: FromQueryResult,
+    {
+        Selector {
+            query: self.query,
+            selector: SelectModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema::<E>,
+            with: Some(self.with),
+            tenant_join: no_tenant_join,
+        }
+    }
+
+    /// Get a selectable Model as a [JsonValue] for SQL JSON operations
+    #[cfg(feature = "with-json")]
+    pub fn into_json(self) -> Selector<SelectModel<JsonValue>> {
+        self.into_model()
+    }
+
+    /// Get one Model from the SELECT query
+    pub async fn one<C>(self, db: &C) -> Result<Option<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model().one(db).await
+    }
+
+    /// Get all Models from the SELECT query
+    pub async fn all<C>(self, db: &C) -> Result<Vec<E::Model>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model().all(db).await
+    }
+
+    /// Stream the results of a SELECT operation on a Model
+    pub async fn stream<'a: 'b, 'b, C>(
+        self,
+        db: &'a C,
+    ) -> Result<impl Stream<Item = Result<E::Model, DbErr>> + 'b + Send, DbErr>
+    where
+        C: ConnectionTrait + StreamTrait + Send,
+    {
+        self.into_model().stream(db).await
+    }
 }
 
 impl<E, F> SelectTwo<E, F>
@@ -295,6 +426,10 @@ where
         Selector {
             query: self.query,
             selector: SelectTwoModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema_two::<E, F>,
+            with: None,
+            tenant_join: tenant_join::<F>,
         }
     }
 
@@ -304,6 +439,10 @@ where
         Selector {
             query: self.query,
             selector: SelectTwoModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema_two::<E, F>,
+            with: None,
+            tenant_join: tenant_join::<F>,
         }
     }
 
@@ -333,6 +472,66 @@ where
     {
         self.into_model().stream(db).await
     }
+
+    /// Check if the SELECT query matches any row
+    pub async fn exists<C>(self, db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>().exists(db).await
+    }
+
+    /// Sum up the values of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn sum<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>()
+            .aggregate(Func::sum, expr, select_two_column_alias::<E, F>, db)
+            .await
+    }
+
+    /// Average the values of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn avg<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>()
+            .aggregate(Func::avg, expr, select_two_column_alias::<E, F>, db)
+            .await
+    }
+
+    /// Get the minimum value of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn min<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>()
+            .aggregate(Func::min, expr, select_two_column_alias::<E, F>, db)
+            .await
+    }
+
+    /// Get the maximum value of an expression over all rows of the SELECT query.
+    /// `None` if no row is matched.
+    pub async fn max<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>()
+            .aggregate(Func::max, expr, select_two_column_alias::<E, F>, db)
+            .await
+    }
 }
 
 impl<E, F> SelectTwoMany<E, F>
@@ -349,6 +548,10 @@ where
         Selector {
             query: self.query,
             selector: SelectTwoModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema_two::<E, F>,
+            with: None,
+            tenant_join: tenant_join::<F>,
         }
     }
 
@@ -358,6 +561,10 @@ where
         Selector {
             query: self.query,
             selector: SelectTwoModel { model: PhantomData },
+            tenant_filter: tenant_filter::<E>,
+            schema_qualifier: qualify_schema_two::<E, F>,
+            with: None,
+            tenant_join: tenant_join::<F>,
         }
     }
 
@@ -394,8 +601,42 @@ where
     // so the correct way is actually perform query in two stages
     // paginate the parent model and then populate the children
 
-    // pub fn count()
-    // we should only count the number of items of the parent model
+    /// Count the number of distinct parent models matched by the select operation
+    pub async fn count<C>(self, db: &C) -> Result<u64, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        // we should only count the number of items of the parent model
+        let mut query = self
+            .into_model::<E::Model, F::Model>()
+            .filter_tenant(db)
+            .query;
+        // ordering by the columns which are not selected is rejected together with DISTINCT
+        query.clear_selects().clear_order_by().distinct();
+        for key in E::PrimaryKey::iter() {
+            query.column(key.into_column().as_column_ref());
+        }
+        Paginator {
+            query,
+            page: 0,
+            page_size: 1,
+            db,
+            selector: PhantomData::<SelectModel<E::Model>>,
+            schema_qualifier: qualify_schema_two::<E, F>,
+            with: None,
+            tenant_join: tenant_join::<F>,
+        }
+        .num_items()
+        .await
+    }
+
+    /// Check if the select operation matches any parent model
+    pub async fn exists<C>(self, db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        self.into_model::<E::Model, F::Model>().exists(db).await
+    }
 }
 
 impl<S> Selector<S>
@@ -415,18 +656,39 @@ where
                 columns: PhantomData,
                 model: PhantomData,
             },
+            tenant_filter: no_tenant_filter,
+            schema_qualifier: no_schema_qualifier,
+            with: None,
+            tenant_join: no_tenant_join,
         }
     }
 
+    /// Restrict the query to the tenant bound to the connection, if any
+    pub(crate) fn filter_tenant<C>(mut self, db: &C) -> Self
+    where
+        C: ConnectionTrait,
+    {
+        if let Some(condition) = db.tenant().and_then(self.tenant_filter) {
+            self.query.cond_where(condition);
+        }
+        self
+    }
+
     fn into_selector_raw<C>(self, db: &C) -> SelectorRaw<S>
     where
         C: ConnectionTrait,
     {
-        let builder = db.get_database_backend();
-        let stmt = builder.build(&self.query);
+        let query = self.filter_tenant(db);
+        let stmt = build_select_with(
+            &query.with,
+            &query.query,
+            query.schema_qualifier,
+            query.tenant_join,
+            db,
+        );
         SelectorRaw {
             stmt,
-            selector: self.selector,
+            selector: query.selector,
         }
     }
 
@@ -459,6 +721,270 @@ where
     {
         self.into_selector_raw(db).stream(db).await
     }
+
+    /// Check if the Select query matches any row
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![vec![cake::Model {
+    /// #         id: 1,
+    /// #         name: "Cake".to_owned(),
+    /// #     }]])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake};
+    ///
+    /// let exists = cake::Entity::find()
+    ///     .filter(cake::Column::Name.contains("cheese"))
+    ///     .exists(&db)
+    ///     .await?;
+    ///
+    /// assert!(exists);
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![Transaction::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."name" LIKE $1 LIMIT $2"#,
+    ///         vec!["%cheese%".into(), 1u64.into()]
+    ///     ),]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists<C>(self, db: &C) -> Result<bool, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut query = self.filter_tenant(db);
+        query.query.limit(1);
+        let stmt = build_select_with(
+            &query.with,
+            &query.query,
+            query.schema_qualifier,
+            query.tenant_join,
+            db,
+        );
+        let row = db.query_one(stmt).await?;
+        Ok(row.is_some())
+    }
+
+    /// Sum up the values of an expression over all rows of the Select query.
+    /// `None` if no row is matched.
+    ///
+    /// The query is wrapped as a subquery, so the expression may only refer to
+    /// the columns it selects, e.g. the grouped columns of a query with `GROUP BY`.
+    /// ```
+    /// # use sea_orm::{error::*, tests_cfg::*, *};
+    /// #
+    /// # #[smol_potat::main]
+    /// # #[cfg(feature = "mock")]
+    /// # pub async fn main() -> Result<(), DbErr> {
+    /// #
+    /// # let db = MockDatabase::new(DbBackend::Postgres)
+    /// #     .append_query_results(vec![
+    /// #         vec![maplit::btreemap! {
+    /// #             "aggregate" => Into::<Value>::into(3i64),
+    /// #         }],
+    /// #         vec![maplit::btreemap! {
+    /// #             "aggregate" => Into::<Value>::into(6i64),
+    /// #         }],
+    /// #     ])
+    /// #     .into_connection();
+    /// #
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit};
+    ///
+    /// let sum: Option<i64> = fruit::Entity::find()
+    ///     .filter(fruit::Column::CakeId.eq(1))
+    ///     .sum(fruit::Column::Id, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(sum, Some(3));
+    ///
+    /// let sum: Option<i64> = fruit::Entity::find()
+    ///     .select_only()
+    ///     .column(fruit::Column::CakeId)
+    ///     .group_by(fruit::Column::CakeId)
+    ///     .sum(fruit::Column::CakeId, &db)
+    ///     .await?;
+    ///
+    /// assert_eq!(sum, Some(6));
+    ///
+    /// assert_eq!(
+    ///     db.into_transaction_log(),
+    ///     vec![
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             [
+    ///                 r#"SELECT SUM("sub_query"."id") AS "aggregate" FROM"#,
+    ///                 r#"(SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id""#,
+    ///                 r#"FROM "fruit" WHERE "fruit"."cake_id" = $1) AS "sub_query""#,
+    ///             ]
+    ///             .join(" ")
+    ///             .as_str(),
+    ///             vec![1i32.into()]
+    ///         ),
+    ///         Transaction::from_sql_and_values(
+    ///             DbBackend::Postgres,
+    ///             [
+    ///                 r#"SELECT SUM("sub_query"."cake_id") AS "aggregate" FROM"#,
+    ///                 r#"(SELECT "fruit"."cake_id" FROM "fruit" GROUP BY "fruit"."cake_id") AS "sub_query""#,
+    ///             ]
+    ///             .join(" ")
+    ///             .as_str(),
+    ///             vec![]
+    ///         ),
+    ///     ]
+    /// );
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sum<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.aggregate(Func::sum, expr, same_column_alias, db).await
+    }
+
+    /// Average the values of an expression over all rows of the Select query.
+    /// `None` if no row is matched.
+    pub async fn avg<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.aggregate(Func::avg, expr, same_column_alias, db).await
+    }
+
+    /// Get the minimum value of an expression over all rows of the Select query.
+    /// `None` if no row is matched.
+    pub async fn min<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.aggregate(Func::min, expr, same_column_alias, db).await
+    }
+
+    /// Get the maximum value of an expression over all rows of the Select query.
+    /// `None` if no row is matched.
+    pub async fn max<T, X, C>(self, expr: X, db: &C) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        self.aggregate(Func::max, expr, same_column_alias, db).await
+    }
+
+    /// Evaluate the aggregate function over the rows of the Select query, which is wrapped
+    /// unchanged as a subquery such that grouping, distinct and limit are respected.
+    /// The columns of the expression refer to the columns selected by the subquery,
+    /// named by `column_alias`.
+    async fn aggregate<T, X, C>(
+        self,
+        func: fn(SimpleExpr) -> SimpleExpr,
+        expr: X,
+        column_alias: ColumnAlias,
+        db: &C,
+    ) -> Result<Option<T>, DbErr>
+    where
+        T: TryGetable,
+        X: IntoSimpleExpr,
+        C: ConnectionTrait,
+    {
+        let Self {
+            query,
+            schema_qualifier,
+            with,
+            tenant_join,
+            ..
+        } = self.filter_tenant(db);
+        let sub_query = Alias::new("sub_query");
+        let expr = sub_query_expr(expr.into_simple_expr(), &sub_query, column_alias);
+        let stmt = build_select_with(
+            &with,
+            SelectStatement::new()
+                .expr_as(func(expr), Alias::new("aggregate"))
+                .from_subquery(query, sub_query),
+            schema_qualifier,
+            tenant_join,
+            db,
+        );
+        match db.query_one(stmt).await? {
+            Some(res) => res.try_get("", "aggregate"),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Names the column selected by a query for a column of a table
+type ColumnAlias = fn(Option<&DynIden>, DynIden) -> DynIden;
+
+fn same_column_alias(_: Option<&DynIden>, col: DynIden) -> DynIden {
+    col
+}
+
+/// The columns of the first and second Entity of a [SelectTwo] are selected
+/// with the prefixes of [SelectA] and [SelectB]
+fn select_two_column_alias<E, F>(tbl: Option<&DynIden>, col: DynIden) -> DynIden
+where
+    E: EntityTrait,
+    F: EntityTrait,
+{
+    let tbl = match tbl {
+        Some(tbl) => tbl.to_string(),
+        None => return col,
+    };
+    let prefix = if tbl == E::default().table_name() {
+        SelectA.as_str()
+    } else if tbl == F::default().table_name() {
+        SelectB.as_str()
+    } else {
+        return col;
+    };
+    SeaRc::new(Alias::new(&format!("{}{}", prefix, col.to_string())))
+}
+
+/// Make the column references of an expression refer to the columns selected by a subquery
+fn sub_query_expr(expr: SimpleExpr, sub_query: &Alias, column_alias: ColumnAlias) -> SimpleExpr {
+    let rewrite = |expr| sub_query_expr(expr, sub_query, column_alias);
+    match expr {
+        SimpleExpr::Column(col) => {
+            let (tbl, col) = match col {
+                ColumnRef::Column(col) => (None, col),
+                ColumnRef::TableColumn(tbl, col) | ColumnRef::SchemaTableColumn(_, tbl, col) => {
+                    (Some(tbl), col)
+                }
+                col => return SimpleExpr::Column(col),
+            };
+            SimpleExpr::Column(ColumnRef::TableColumn(
+                SeaRc::new(sub_query.clone()),
+                column_alias(tbl.as_ref(), col),
+            ))
+        }
+        SimpleExpr::Tuple(exprs) => SimpleExpr::Tuple(exprs.into_iter().map(rewrite).collect()),
+        SimpleExpr::Unary(op, expr) => SimpleExpr::Unary(op, Box::new(rewrite(*expr))),
+        SimpleExpr::FunctionCall(func, args) => {
+            SimpleExpr::FunctionCall(func, args.into_iter().map(rewrite).collect())
+        }
+        SimpleExpr::Binary(left, op, right) => {
+            SimpleExpr::Binary(Box::new(rewrite(*left)), op, Box::new(rewrite(*right)))
+        }
+        SimpleExpr::AsEnum(name, expr) => SimpleExpr::AsEnum(name, Box::new(rewrite(*expr))),
+        expr => expr,
+    }
 }
 
 impl<S> SelectorRaw<S>
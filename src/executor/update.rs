@@ -1,6 +1,7 @@
 use crate::{
-    cast_enum_as_text, error::*, ActiveModelTrait, ConnectionTrait, EntityTrait, IntoActiveModel,
-    Iterable, SelectModel, SelectorRaw, Statement, UpdateMany, UpdateOne,
+    cast_enum_as_text, entity_table_ref, error::*, find_by_id_unscoped, tenant_filter,
+    ActiveModelTrait, ConnectionTrait, EntityTrait, IntoActiveModel, Iterable, SelectModel,
+    SelectorRaw, Statement, UpdateBatch, UpdateMany, UpdateOne,
 };
 use sea_query::{Expr, FromValueTuple, Query, UpdateStatement};
 use std::future::Future;
@@ -30,7 +31,8 @@ where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_update_and_return_updated(self.query, self.model, db).await
+        let query = bind::<A::Entity, _>(self.query, db);
+        exec_update_and_return_updated(query, self.model, db).await
     }
 }
 
@@ -44,7 +46,57 @@ where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_update_only(self.query, db)
+        exec_update_only(bind::<E, _>(self.query, db), db)
+    }
+
+    /// Execute an update operation and return the updated models (use `RETURNING` syntax)
+    ///
+    /// Returns [`DbErr::BackendNotSupported`] if the database doesn't support `RETURNING`
+    pub fn exec_with_returning<C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<Vec<E::Model>, DbErr>> + 'a
+    where
+        C: ConnectionTrait,
+    {
+        exec_update_with_returning::<E, _>(bind::<E, _>(self.query, db), db)
+    }
+}
+
+impl<A> UpdateBatch<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Execute an update operation on multiple ActiveModels, one statement per chunk
+    ///
+    /// The statements are executed one after another; run this inside a transaction
+    /// if the whole batch must be applied atomically.
+    ///
+    /// Models carrying a version only update the row still at that version. If some of them
+    /// did not, the other rows are still updated and [`DbErr::StaleRecord`] is returned.
+    pub async fn exec<C>(self, db: &C) -> Result<UpdateResult, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let mut rows_affected = 0;
+        let (mut versioned, mut stale) = (0, 0);
+        let table_ref = entity_table_ref::<A::Entity, _>(db);
+        let filter = db.tenant().and_then(tenant_filter::<A::Entity>);
+        for (statement, expected) in self.build_for(db.get_database_backend(), table_ref, filter) {
+            let affected = exec_update(statement, db, false).await?.rows_affected;
+            if let Some(expected) = expected {
+                versioned += expected;
+                stale += (expected as u64).saturating_sub(affected);
+            }
+            rows_affected += affected;
+        }
+        if stale > 0 {
+            return Err(DbErr::StaleRecord(format!(
+                "{} of the {} versioned records have been modified since they were read",
+                stale, versioned
+            )));
+        }
+        Ok(UpdateResult { rows_affected })
     }
 }
 
@@ -73,6 +125,20 @@ impl Updater {
     }
 }
 
+/// Prepare the update for the connection, restricting it to the rows of the tenant
+/// bound to the connection and moving the table into the schema bound to it
+fn bind<E, C>(mut query: UpdateStatement, db: &C) -> UpdateStatement
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    if let Some(condition) = db.tenant().and_then(tenant_filter::<E>) {
+        query.cond_where(condition);
+    }
+    query.table(entity_table_ref::<E, _>(db));
+    query
+}
+
 async fn exec_update_only<C>(query: UpdateStatement, db: &C) -> Result<UpdateResult, DbErr>
 where
     C: ConnectionTrait,
@@ -80,6 +146,29 @@ where
     Updater::new(query).exec(db).await
 }
 
+async fn exec_update_with_returning<E, C>(
+    mut query: UpdateStatement,
+    db: &C,
+) -> Result<Vec<E::Model>, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let db_backend = db.get_database_backend();
+    if !db.support_update_returning() {
+        return Err(DbErr::BackendNotSupported {
+            db: db_backend.as_str(),
+            ctx: "RETURNING",
+        });
+    }
+    let returning =
+        Query::returning().exprs(E::Column::iter().map(|c| cast_enum_as_text(Expr::col(c), &c)));
+    query.returning(returning);
+    SelectorRaw::<SelectModel<E::Model>>::from_statement(db_backend.build(&query))
+        .all(db)
+        .await
+}
+
 async fn exec_update_and_return_updated<A, C>(
     mut query: UpdateStatement,
     model: A,
@@ -89,7 +178,20 @@ where
     A: ActiveModelTrait,
     C: ConnectionTrait,
 {
-    match db.support_returning() {
+    // If the model carries a version, a row not being updated means it has been modified meanwhile
+    let versioned = <A::Entity as EntityTrait>::version_column()
+        .map(|col| {
+            let av = model.get(col);
+            av.is_set() || av.is_unchanged()
+        })
+        .unwrap_or(false);
+    let not_updated = |err: DbErr| match err {
+        DbErr::RecordNotFound(_) if versioned => {
+            DbErr::StaleRecord("The record has been modified since it was read".to_owned())
+        }
+        err => err,
+    };
+    match db.support_update_returning() {
         true => {
             let returning = Query::returning().exprs(
                 <A::Entity as EntityTrait>::Column::iter()
@@ -106,19 +208,23 @@ where
             // If we got `None` then we are updating a row that does not exist.
             match found {
                 Some(model) => Ok(model),
-                None => Err(DbErr::RecordNotFound(
+                None => Err(not_updated(DbErr::RecordNotFound(
                     "None of the database rows are affected".to_owned(),
-                )),
+                ))),
             }
         }
         false => {
             // If we updating a row that does not exist then an error will be thrown here.
-            Updater::new(query).check_record_exists().exec(db).await?;
+            Updater::new(query)
+                .check_record_exists()
+                .exec(db)
+                .await
+                .map_err(not_updated)?;
             let primary_key_value = match model.get_primary_key_value() {
                 Some(val) => FromValueTuple::from_value_tuple(val),
                 None => return Err(DbErr::UpdateGetPrimaryKey),
             };
-            let found = <A::Entity as EntityTrait>::find_by_id(primary_key_value)
+            let found = find_by_id_unscoped::<A::Entity>(primary_key_value)
                 .one(db)
                 .await?;
             // If we cannot select the updated row from db by the cached primary key
@@ -155,7 +261,7 @@ where
 mod tests {
     use crate::{entity::prelude::*, tests_cfg::*, *};
     use pretty_assertions::assert_eq;
-    use sea_query::Expr;
+    use sea_query::{Expr, Value};
 
     #[smol_potat::test]
     async fn update_record_not_found_1() -> Result<(), DbErr> {
@@ -276,4 +382,168 @@ mod tests {
 
         Ok(())
     }
+
+    #[smol_potat::test]
+    async fn update_many_with_returning_1() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit::Model {
+                    id: 1,
+                    name: "Apple".to_owned(),
+                    cake_id: None,
+                },
+                fruit::Model {
+                    id: 2,
+                    name: "Green Apple".to_owned(),
+                    cake_id: None,
+                },
+            ]])
+            .into_connection();
+
+        let updated = fruit::Entity::update_many()
+            .col_expr(fruit::Column::CakeId, Expr::value(Value::Int(None)))
+            .filter(fruit::Column::Name.contains("Apple"))
+            .exec_with_returning(&db)
+            .await?;
+
+        assert_eq!(
+            updated.into_iter().map(|m| m.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                r#"UPDATE "fruit" SET "cake_id" = $1 WHERE "fruit"."name" LIKE $2 RETURNING "id", "name", "cake_id""#,
+                vec![Value::Int(None), "%Apple%".into()]
+            )]
+        );
+
+        let db = MockDatabase::new(DbBackend::MySql).into_connection();
+
+        assert_eq!(
+            fruit::Entity::update_many()
+                .col_expr(fruit::Column::CakeId, Expr::value(Value::Int(None)))
+                .exec_with_returning(&db)
+                .await,
+            Err(DbErr::BackendNotSupported {
+                db: "MySQL",
+                ctx: "RETURNING",
+            })
+        );
+        assert_eq!(db.into_transaction_log(), vec![]);
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_stale_record_1() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![ingredient::Model {
+                    id: 1,
+                    name: "Flour".to_owned(),
+                    version: 4,
+                }],
+                vec![],
+            ])
+            .into_connection();
+
+        let model = ingredient::Model {
+            id: 1,
+            name: "Sugar".to_owned(),
+            version: 3,
+        };
+
+        assert_eq!(
+            ingredient::ActiveModel {
+                name: Set("Flour".to_owned()),
+                ..model.clone().into_active_model()
+            }
+            .update(&db)
+            .await?,
+            ingredient::Model {
+                id: 1,
+                name: "Flour".to_owned(),
+                version: 4,
+            }
+        );
+
+        assert_eq!(
+            ingredient::ActiveModel {
+                name: Set("Salt".to_owned()),
+                ..model.into_active_model()
+            }
+            .update(&db)
+            .await,
+            Err(DbErr::StaleRecord(
+                "The record has been modified since it was read".to_owned()
+            ))
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "ingredient" SET "name" = $1, "version" = "version" + $2 WHERE "ingredient"."id" = $3 AND "ingredient"."version" = $4 RETURNING "id", "name", "version""#,
+                    vec!["Flour".into(), 1i32.into(), 1i32.into(), 3i32.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::Postgres,
+                    r#"UPDATE "ingredient" SET "name" = $1, "version" = "version" + $2 WHERE "ingredient"."id" = $3 AND "ingredient"."version" = $4 RETURNING "id", "name", "version""#,
+                    vec!["Salt".into(), 1i32.into(), 1i32.into(), 3i32.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[smol_potat::test]
+    async fn update_out_of_default_scope() -> Result<(), DbErr> {
+        let discontinued = cookie::Model {
+            id: 1,
+            name: "Shortbread".to_owned(),
+            discontinued: true,
+            cake_id: None,
+        };
+        let db = MockDatabase::new(DbBackend::MySql)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .append_query_results(vec![vec![discontinued.clone()]])
+            .into_connection();
+
+        assert_eq!(
+            cookie::ActiveModel {
+                id: Unchanged(1),
+                discontinued: Set(true),
+                ..Default::default()
+            }
+            .update(&db)
+            .await?,
+            discontinued
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"UPDATE `cookie` SET `discontinued` = ? WHERE `cookie`.`id` = ? AND `cookie`.`discontinued` = ?"#,
+                    vec![true.into(), 1i32.into(), false.into()]
+                ),
+                Transaction::from_sql_and_values(
+                    DbBackend::MySql,
+                    r#"SELECT `cookie`.`id`, `cookie`.`name`, `cookie`.`discontinued`, `cookie`.`cake_id` FROM `cookie` WHERE `cookie`.`id` = ? LIMIT ?"#,
+                    vec![1i32.into(), 1u64.into()]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -4,7 +4,10 @@ use crate::{
 };
 use core::marker::PhantomData;
 pub use sea_query::JoinType;
-use sea_query::{Alias, ColumnRef, Iden, Order, SeaRc, SelectExpr, SelectStatement, SimpleExpr};
+use sea_query::{
+    Alias, ColumnRef, Iden, Order, OrderedStatement, PostgresQueryBuilder, SeaRc, SelectExpr,
+    SelectStatement, SimpleExpr, UnionType,
+};
 
 macro_rules! select_def {
     ( $ident: ident, $str: expr ) => {
@@ -90,6 +93,72 @@ where
         self = self.apply_alias(SelectA.as_str());
         SelectTwoMany::new(self.into_query())
     }
+
+    /// Combine with the rows of another query using `UNION`, removing duplicated rows.
+    ///
+    /// The combined query is wrapped in a subquery aliased as the table of the Entity,
+    /// so that it can be further filtered, ordered, paginated or traversed with a cursor.
+    /// An operand that is ordered, limited or offset is wrapped in a subquery of its own,
+    /// so that it keeps them.
+    ///
+    /// The other query may select from another Entity, as long as both select the same columns,
+    /// for instance with [`select_only`](crate::QuerySelect::select_only) to be read
+    /// with [`into_model`](Select::into_model).
+    pub fn union<F>(self, other: Select<F>) -> Self
+    where
+        F: EntityTrait,
+    {
+        self.combine(UnionType::Distinct, other)
+    }
+
+    /// Combine with the rows of another query using `UNION ALL`, keeping duplicated rows.
+    pub fn union_all<F>(self, other: Select<F>) -> Self
+    where
+        F: EntityTrait,
+    {
+        self.combine(UnionType::All, other)
+    }
+
+    /// Keep only the rows also returned by another query, using `INTERSECT`.
+    ///
+    /// MySQL supports `INTERSECT` since 8.0.31.
+    pub fn intersect<F>(self, other: Select<F>) -> Self
+    where
+        F: EntityTrait,
+    {
+        self.combine(UnionType::Intersect, other)
+    }
+
+    /// Remove the rows also returned by another query, using `EXCEPT`.
+    ///
+    /// MySQL supports `EXCEPT` since 8.0.31.
+    pub fn except<F>(self, other: Select<F>) -> Self
+    where
+        F: EntityTrait,
+    {
+        self.combine(UnionType::Except, other)
+    }
+
+    fn combine<F>(self, union_type: UnionType, other: Select<F>) -> Self
+    where
+        F: EntityTrait,
+    {
+        let mut query = combine_operand(self.query, E::default().table_name());
+        query.union(
+            union_type,
+            combine_operand(other.query, F::default().table_name()),
+        );
+
+        let mut select = Self {
+            query: SelectStatement::new(),
+            entity: PhantomData,
+        };
+        select
+            .query
+            .column(ColumnRef::Asterisk)
+            .from_subquery(query, Alias::new(E::default().table_name()));
+        select
+    }
 }
 
 impl<E, F> SelectTwo<E, F>
@@ -141,6 +210,21 @@ where
     }
 }
 
+/// Wrap an operand of a set operation in a subquery if it is ordered, limited or offset,
+/// as these clauses would otherwise apply to the whole combined query
+fn combine_operand(query: SelectStatement, alias: &str) -> SelectStatement {
+    let mut unbounded = query.clone();
+    unbounded.clear_order_by().reset_limit().reset_offset();
+    // the statement only tells whether it has these clauses when rendered
+    if unbounded.to_string(PostgresQueryBuilder) == query.to_string(PostgresQueryBuilder) {
+        return query;
+    }
+    SelectStatement::new()
+        .column(ColumnRef::Asterisk)
+        .from_subquery(query, Alias::new(alias))
+        .to_owned()
+}
+
 fn prepare_select_two<F, S>(selector: &mut S)
 where
     F: EntityTrait,
@@ -159,7 +243,9 @@ where
 #[cfg(test)]
 mod tests {
     use crate::tests_cfg::{cake, fruit};
-    use crate::{ColumnTrait, DbBackend, EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+    use crate::{
+        ColumnTrait, DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    };
 
     #[test]
     fn alias_1() {
@@ -244,4 +330,67 @@ mod tests {
             ].join(" ")
         );
     }
+
+    #[test]
+    fn union_1() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter(cake::Column::Name.contains("cheese"))
+                .order_by_asc(cake::Column::Name)
+                .union(cake::Entity::find().filter(cake::Column::Id.lt(3)).limit(5))
+                .filter(cake::Column::Id.ne(2))
+                .order_by_desc(cake::Column::Id)
+                .limit(10)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT * FROM (SELECT * FROM (SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`name` LIKE '%cheese%'",
+                "ORDER BY `cake`.`name` ASC) AS `cake`",
+                "UNION SELECT * FROM (SELECT `cake`.`id`, `cake`.`name` FROM `cake` WHERE `cake`.`id` < 3 LIMIT 5) AS `cake`) AS `cake`",
+                "WHERE `cake`.`id` <> 2 ORDER BY `cake`.`id` DESC LIMIT 10",
+            ].join(" ")
+        );
+    }
+
+    #[test]
+    fn union_all_1() {
+        assert_eq!(
+            cake::Entity::find()
+                .union_all(cake::Entity::find())
+                .intersect(cake::Entity::find().filter(cake::Column::Id.gt(1)))
+                .except(cake::Entity::find().filter(cake::Column::Id.eq(4)))
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT * FROM (SELECT * FROM (SELECT * FROM (SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"UNION ALL SELECT "cake"."id", "cake"."name" FROM "cake") AS "cake""#,
+                r#"INTERSECT SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" > 1) AS "cake""#,
+                r#"EXCEPT SELECT "cake"."id", "cake"."name" FROM "cake" WHERE "cake"."id" = 4) AS "cake""#,
+            ].join(" ")
+        );
+    }
+    #[test]
+    fn union_2() {
+        assert_eq!(
+            cake::Entity::find()
+                .select_only()
+                .column(cake::Column::Id)
+                .column(cake::Column::Name)
+                .union(
+                    fruit::Entity::find()
+                        .select_only()
+                        .column(fruit::Column::Id)
+                        .column(fruit::Column::Name)
+                )
+                .order_by_asc(cake::Column::Name)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"SELECT * FROM (SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"UNION SELECT "fruit"."id", "fruit"."name" FROM "fruit") AS "cake""#,
+                r#"ORDER BY "cake"."name" ASC"#,
+            ]
+            .join(" ")
+        );
+    }
 }
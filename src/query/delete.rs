@@ -3,7 +3,7 @@ use crate::{
     QueryFilter, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::DeleteStatement;
+use sea_query::{Condition, DeleteStatement, IntoCondition};
 
 /// Defines the structure for a delete operation
 #[derive(Clone, Debug)]
@@ -17,6 +17,9 @@ where
 {
     pub(crate) query: DeleteStatement,
     pub(crate) model: A,
+    pub(crate) force: bool,
+    /// The conditions of the query, from which a soft delete is built as an UPDATE
+    pub(crate) condition: Condition,
 }
 
 /// Perform a delete operation on multiple models
@@ -27,6 +30,9 @@ where
 {
     pub(crate) query: DeleteStatement,
     pub(crate) entity: PhantomData<E>,
+    pub(crate) force: bool,
+    /// The conditions of the query, from which a soft delete is built as an UPDATE
+    pub(crate) condition: Condition,
 }
 
 impl Delete {
@@ -71,6 +77,8 @@ impl Delete {
                 .from_table(A::Entity::default().table_ref())
                 .to_owned(),
             model: model.into_active_model(),
+            force: false,
+            condition: Condition::all(),
         };
         myself.prepare()
     }
@@ -97,6 +105,8 @@ impl Delete {
                 .from_table(entity.table_ref())
                 .to_owned(),
             entity: PhantomData,
+            force: false,
+            condition: Condition::all(),
         }
     }
 }
@@ -117,6 +127,23 @@ where
         }
         self
     }
+
+    /// Delete the row even if the Entity is soft deleting
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+}
+
+impl<E> DeleteMany<E>
+where
+    E: EntityTrait,
+{
+    /// Delete the rows even if the Entity is soft deleting
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
 }
 
 impl<A> QueryFilter for DeleteOne<A>
@@ -128,6 +155,15 @@ where
     fn query(&mut self) -> &mut DeleteStatement {
         &mut self.query
     }
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        self.condition = self.condition.add(condition.clone());
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<E> QueryFilter for DeleteMany<E>
@@ -139,6 +175,15 @@ where
     fn query(&mut self) -> &mut DeleteStatement {
         &mut self.query
     }
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        self.condition = self.condition.add(condition.clone());
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<A> QueryTrait for DeleteOne<A>
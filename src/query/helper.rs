@@ -3,10 +3,13 @@ use crate::{
     ModelTrait, PrimaryKeyToColumn, RelationDef,
 };
 use sea_query::{
-    Alias, Expr, Iden, IntoCondition, IntoIden, LockType, SeaRc, SelectExpr, SelectStatement,
-    SimpleExpr, TableRef,
+    Alias, Expr, Func, Iden, IntoCondition, IntoIden, LockType, SeaRc, SelectExpr, SelectStatement,
+    SimpleExpr, TableRef, WindowSelectType,
+};
+pub use sea_query::{
+    Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement, OverStatement,
+    WindowStatement,
 };
-pub use sea_query::{Condition, ConditionalStatement, DynIden, JoinType, Order, OrderedStatement};
 
 use sea_query::IntoColumnRef;
 
@@ -295,6 +298,167 @@ pub trait QuerySelect: Sized {
         self
     }
 
+    /// Add a select expression evaluated over a window, with alias.
+    /// Aggregate expressions over an ordered window give running totals.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .column_as_window(
+    ///             fruit::Column::Id.sum(),
+    ///             WindowStatement::new()
+    ///                 .add_partition_by(fruit::Column::CakeId.into_simple_expr())
+    ///                 .order_by_expr(fruit::Column::Id.into_simple_expr(), Order::Asc)
+    ///                 .to_owned(),
+    ///             "running_total"
+    ///         )
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id","#,
+    ///         r#"SUM("fruit"."id") OVER ( PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."id" ASC ) AS "running_total""#,
+    ///         r#"FROM "fruit""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn column_as_window<C, I>(mut self, col: C, window: WindowStatement, alias: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIdentity,
+    {
+        self.query().expr(SelectExpr {
+            expr: col.into_simple_expr(),
+            alias: Some(SeaRc::new(alias.into_identity())),
+            window: Some(WindowSelectType::Query(window)),
+        });
+        self
+    }
+
+    /// Add a `ROW_NUMBER()` over a window, with alias
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::Name)
+    ///         .row_number(
+    ///             WindowStatement::new()
+    ///                 .add_partition_by(fruit::Column::CakeId.into_simple_expr())
+    ///                 .order_by_expr(fruit::Column::Name.into_simple_expr(), Order::Desc)
+    ///                 .to_owned(),
+    ///             "row_num"
+    ///         )
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     [
+    ///         "SELECT `fruit`.`name`,",
+    ///         "ROW_NUMBER() OVER ( PARTITION BY `fruit`.`cake_id` ORDER BY `fruit`.`name` DESC ) AS `row_num`",
+    ///         "FROM `fruit`",
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn row_number<I>(self, window: WindowStatement, alias: I) -> Self
+    where
+        I: IntoIdentity,
+    {
+        self.column_as_window(window_func("ROW_NUMBER", []), window, alias)
+    }
+
+    /// Add a `RANK()` over a window, with alias
+    fn rank<I>(self, window: WindowStatement, alias: I) -> Self
+    where
+        I: IntoIdentity,
+    {
+        self.column_as_window(window_func("RANK", []), window, alias)
+    }
+
+    /// Add a `DENSE_RANK()` over a window, with alias
+    fn dense_rank<I>(self, window: WindowStatement, alias: I) -> Self
+    where
+        I: IntoIdentity,
+    {
+        self.column_as_window(window_func("DENSE_RANK", []), window, alias)
+    }
+
+    /// Add the value of an expression `offset` rows before the current row in a window, with alias.
+    /// `NULL` if there is no such row.
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend, Statement};
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .column(fruit::Column::Id)
+    ///         .lag(
+    ///             fruit::Column::Name,
+    ///             1,
+    ///             WindowStatement::new()
+    ///                 .order_by_expr(fruit::Column::Id.into_simple_expr(), Order::Asc)
+    ///                 .to_owned(),
+    ///             "previous_name"
+    ///         )
+    ///         .lead(
+    ///             fruit::Column::Name,
+    ///             2,
+    ///             WindowStatement::new()
+    ///                 .order_by_expr(fruit::Column::Id.into_simple_expr(), Order::Asc)
+    ///                 .to_owned(),
+    ///             "next_next_name"
+    ///         )
+    ///         .build(DbBackend::Sqlite)
+    ///         .to_string(),
+    ///     [
+    ///         r#"SELECT "fruit"."id","#,
+    ///         r#"LAG("fruit"."name", 1) OVER (  ORDER BY "fruit"."id" ASC ) AS "previous_name","#,
+    ///         r#"LEAD("fruit"."name", 2) OVER (  ORDER BY "fruit"."id" ASC ) AS "next_next_name""#,
+    ///         r#"FROM "fruit""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    ///
+    /// // The offset is bound as an `integer`, as Postgres has no `LAG(anyelement, bigint)`
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .select_only()
+    ///         .lag(fruit::Column::Name, 1, WindowStatement::new(), "previous_name")
+    ///         .build(DbBackend::Postgres),
+    ///     Statement::from_sql_and_values(
+    ///         DbBackend::Postgres,
+    ///         r#"SELECT LAG("fruit"."name", $1) OVER (  ) AS "previous_name" FROM "fruit""#,
+    ///         vec![1i32.into()]
+    ///     )
+    /// );
+    /// ```
+    fn lag<C, I>(self, col: C, offset: i32, window: WindowStatement, alias: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIdentity,
+    {
+        self.column_as_window(
+            window_func("LAG", [col.into_simple_expr(), offset.into()]),
+            window,
+            alias,
+        )
+    }
+
+    /// Add the value of an expression `offset` rows after the current row in a window, with alias.
+    /// `NULL` if there is no such row.
+    fn lead<C, I>(self, col: C, offset: i32, window: WindowStatement, alias: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIdentity,
+    {
+        self.column_as_window(
+            window_func("LEAD", [col.into_simple_expr(), offset.into()]),
+            window,
+            alias,
+        )
+    }
+
     #[doc(hidden)]
     fn join_join(mut self, join: JoinType, rel: RelationDef, via: Option<RelationDef>) -> Self {
         if let Some(via) = via {
@@ -602,6 +766,10 @@ pub trait QueryFilter: Sized {
     }
 }
 
+fn window_func<const N: usize>(name: &str, args: [SimpleExpr; N]) -> SimpleExpr {
+    Func::cust(Alias::new(name)).args(args)
+}
+
 pub(crate) fn join_condition(mut rel: RelationDef) -> Condition {
     // Use table alias (if any) to construct the join condition
     let from_tbl = match unpack_table_alias(&rel.from_tbl) {
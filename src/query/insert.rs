@@ -1,9 +1,10 @@
 use crate::{
-    cast_text_as_enum, ActiveModelTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
+    cast_text_as_enum, entity_table_ref, ActiveModelTrait, ColumnTrait, ConnectionTrait,
+    EntityName, EntityTrait, IdenStatic, IntoActiveModel, Iterable, PrimaryKeyToColumn,
     PrimaryKeyTrait, QueryTrait,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, InsertStatement, OnConflict, ValueTuple};
+use sea_query::{Expr, InsertStatement, OnConflict, SimpleExpr, Value, ValueTuple};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -13,7 +14,13 @@ where
 {
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
+    pub(crate) set_columns: Vec<bool>,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) primary_keys: Vec<Option<ValueTuple>>,
+    pub(crate) on_conflict: Option<OnConflict>,
+    /// The values of the rows leaving the tenant column `NotSet`, kept to rebuild
+    /// the statement once the tenant of the connection is known
+    pub(crate) tenant_rows: Vec<Vec<SimpleExpr>>,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -37,7 +44,11 @@ where
                 .or_default_values()
                 .to_owned(),
             columns: Vec::new(),
+            set_columns: Vec::new(),
             primary_key: None,
+            primary_keys: Vec::new(),
+            on_conflict: None,
+            tenant_rows: Vec::new(),
             model: PhantomData,
         }
     }
@@ -115,6 +126,22 @@ where
         M: IntoActiveModel<A>,
     {
         let mut am: A = m.into_active_model();
+        let created_at = <A::Entity as EntityTrait>::created_at_column();
+        let timestamp_columns = [created_at, <A::Entity as EntityTrait>::updated_at_column()];
+        // a filled `created_at` is inserted but not counted as set, so that an upsert keeps
+        // the time the existing row was created
+        let mut filled_created_at = None;
+        for col in timestamp_columns.into_iter().flatten() {
+            if am.is_not_set(col) {
+                if let Some(now) = col.current_timestamp() {
+                    am.set(col, now);
+                    if created_at.map(|c| c.as_str() == col.as_str()) == Some(true) {
+                        filled_created_at = Some(col);
+                    }
+                }
+            }
+        }
+        self.primary_keys.push(am.get_primary_key_value());
         self.primary_key =
             if !<<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment() {
                 am.get_primary_key_value()
@@ -127,16 +154,26 @@ where
         for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
             let av = am.take(col);
             let av_has_val = av.is_set() || av.is_unchanged();
+            let av_is_set =
+                av.is_set() && filled_created_at.map(|c| c.as_str() == col.as_str()) != Some(true);
             if columns_empty {
                 self.columns.push(av_has_val);
+                self.set_columns.push(av_is_set);
             } else if self.columns[idx] != av_has_val {
                 panic!("columns mismatch");
+            } else {
+                self.set_columns[idx] |= av_is_set;
             }
             if av_has_val {
                 columns.push(col);
                 values.push(cast_text_as_enum(Expr::val(av.into_value().unwrap()), &col));
             }
         }
+        if let Some(col) = <A::Entity as EntityTrait>::tenant_column() {
+            if !columns.iter().any(|c| c.as_str() == col.as_str()) {
+                self.tenant_rows.push(values.clone());
+            }
+        }
         self.query.columns(columns);
         self.query.values_panic(values);
         self
@@ -198,9 +235,148 @@ where
     /// );
     /// ```
     pub fn on_conflict(mut self, on_conflict: OnConflict) -> Self {
-        self.query.on_conflict(on_conflict);
+        self.query.on_conflict(on_conflict.clone());
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// Update the existing row instead of failing when a row conflicts with the primary key,
+    /// or with the first unique column inserted if the primary key is not inserted.
+    /// Only the columns that are `Set` get updated.
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// let orange = cake::ActiveModel {
+    ///     id: ActiveValue::set(2),
+    ///     name: ActiveValue::set("Orange".to_owned()),
+    /// };
+    /// assert_eq!(
+    ///     cake::Entity::insert(orange)
+    ///         .upsert()
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("id") DO UPDATE SET "name" = "excluded"."name""#,
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if neither the primary key nor any unique column is inserted.
+    pub fn upsert(self) -> Self {
+        let target = self
+            .upsert_target()
+            .expect("neither the primary key nor any unique column is inserted");
+        self.upsert_on(target)
+    }
+
+    /// Update the `Set` columns of the existing row instead of failing
+    /// when a row conflicts with the given columns
+    ///
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::fruit, DbBackend};
+    ///
+    /// let apple = fruit::ActiveModel {
+    ///     name: ActiveValue::set("Apple".to_owned()),
+    ///     cake_id: ActiveValue::set(Some(1)),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     fruit::Entity::insert(apple.clone())
+    ///         .upsert_on([fruit::Column::Name])
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ('Apple', 1) ON CONFLICT ("name") DO UPDATE SET "cake_id" = "excluded"."cake_id""#,
+    /// );
+    /// assert_eq!(
+    ///     fruit::Entity::insert(apple)
+    ///         .upsert_on([fruit::Column::Name])
+    ///         .build(DbBackend::MySql)
+    ///         .to_string(),
+    ///     r#"INSERT INTO `fruit` (`name`, `cake_id`) VALUES ('Apple', 1) ON DUPLICATE KEY UPDATE `cake_id` = VALUES(`cake_id`)"#,
+    /// );
+    /// ```
+    pub fn upsert_on<I>(self, target: I) -> Self
+    where
+        I: IntoIterator<Item = <A::Entity as EntityTrait>::Column>,
+    {
+        let target: Vec<_> = target.into_iter().collect();
+        let mut update_columns: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+            .enumerate()
+            .filter(|(idx, col)| {
+                self.set_columns.get(*idx) == Some(&true)
+                    && !target.iter().any(|t| t.as_str() == col.as_str())
+            })
+            .map(|(_, col)| col)
+            .collect();
+        if update_columns.is_empty() {
+            // Update the conflict target with itself, so that the row is still returned
+            update_columns = target.clone();
+        }
+        self.on_conflict(
+            OnConflict::columns(target)
+                .update_columns(update_columns)
+                .to_owned(),
+        )
+    }
+
+    /// Prepare the statement for the connection, filling the tenant column with the tenant
+    /// bound to the connection and moving the table into the schema bound to it
+    pub(crate) fn bind<C>(self, db: &C) -> Self
+    where
+        C: ConnectionTrait,
+    {
+        let mut insert = self.fill_tenant(db.tenant());
+        insert
+            .query
+            .into_table(entity_table_ref::<A::Entity, _>(db));
+        insert
+    }
+
+    /// Rebuild the statement with the tenant column filled with `tenant`,
+    /// if the rows added leave it `NotSet`
+    fn fill_tenant(mut self, tenant: Option<Value>) -> Self {
+        let (col, tenant) = match (<A::Entity as EntityTrait>::tenant_column(), tenant) {
+            (Some(col), Some(tenant)) if !self.tenant_rows.is_empty() => (col, tenant),
+            _ => return self,
+        };
+        let columns = <A::Entity as EntityTrait>::Column::iter()
+            .zip(self.columns.iter())
+            .filter_map(|(col, has_val)| if *has_val { Some(col) } else { None })
+            .chain([col]);
+        let mut query = InsertStatement::new()
+            .into_table(A::Entity::default().table_ref())
+            .columns(columns)
+            .to_owned();
+        for mut values in std::mem::take(&mut self.tenant_rows) {
+            values.push(cast_text_as_enum(Expr::val(tenant.clone()), &col));
+            query.values_panic(values);
+        }
+        if let Some(on_conflict) = self.on_conflict.clone() {
+            query.on_conflict(on_conflict);
+        }
+        self.query = query;
         self
     }
+
+    /// The primary key if it is inserted, or else the first unique column inserted
+    pub(crate) fn upsert_target(&self) -> Option<Vec<<A::Entity as EntityTrait>::Column>> {
+        let has_value = |col: &<A::Entity as EntityTrait>::Column| {
+            <A::Entity as EntityTrait>::Column::iter()
+                .position(|c| c.as_str() == col.as_str())
+                .and_then(|idx| self.columns.get(idx))
+                == Some(&true)
+        };
+        let primary_key: Vec<_> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .collect();
+        if !primary_key.is_empty() && primary_key.iter().all(has_value) {
+            return Some(primary_key);
+        }
+        <A::Entity as EntityTrait>::Column::iter()
+            .find(|col| col.def().unique && has_value(col))
+            .map(|col| vec![col])
+    }
 }
 
 impl<A> QueryTrait for Insert<A>
@@ -350,4 +526,58 @@ mod tests {
             r#"INSERT INTO "cake" ("id", "name") VALUES (2, 'Orange') ON CONFLICT ("name") DO UPDATE SET "name" = "excluded"."name""#,
         );
     }
+
+    #[test]
+    #[cfg(all(feature = "macros", feature = "with-chrono"))]
+    fn insert_8() {
+        mod comment {
+            use crate as sea_orm;
+            use crate::entity::prelude::*;
+
+            #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+            #[sea_orm(table_name = "comment")]
+            pub struct Model {
+                #[sea_orm(primary_key)]
+                pub id: i32,
+                pub body: String,
+                #[sea_orm(created_at)]
+                pub created_at: DateTimeUtc,
+                #[sea_orm(updated_at)]
+                pub updated_at: DateTimeUtc,
+            }
+
+            #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+            pub enum Relation {}
+
+            impl ActiveModelBehavior for ActiveModel {}
+        }
+
+        let sql = comment::Entity::insert(comment::ActiveModel {
+            id: ActiveValue::set(1),
+            body: ActiveValue::set("First!".to_owned()),
+            ..Default::default()
+        })
+        .upsert()
+        .build(DbBackend::Postgres)
+        .to_string();
+        assert!(sql.starts_with(
+            r#"INSERT INTO "comment" ("id", "body", "created_at", "updated_at") VALUES (1, 'First!', '"#
+        ));
+        assert!(sql.ends_with(
+            r#"ON CONFLICT ("id") DO UPDATE SET "body" = "excluded"."body", "updated_at" = "excluded"."updated_at""#
+        ));
+
+        let sql = comment::Entity::insert(comment::ActiveModel {
+            id: ActiveValue::set(1),
+            body: ActiveValue::set("First!".to_owned()),
+            created_at: ActiveValue::set("2022-01-07T12:11:23Z".parse().unwrap()),
+            ..Default::default()
+        })
+        .upsert()
+        .build(DbBackend::Postgres)
+        .to_string();
+        assert!(sql.ends_with(
+            r#"ON CONFLICT ("id") DO UPDATE SET "body" = "excluded"."body", "created_at" = "excluded"."created_at", "updated_at" = "excluded"."updated_at""#
+        ));
+    }
 }
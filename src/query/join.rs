@@ -1,9 +1,13 @@
 use crate::{
-    cast_enum_as_text, join_tbl_on_condition, unpack_table_ref, EntityTrait, IdenStatic, Iterable,
-    Linked, QuerySelect, Related, Select, SelectA, SelectB, SelectTwo, SelectTwoMany,
+    cast_enum_as_text, join_condition, join_tbl_on_condition, unpack_table_ref, ColumnTrait,
+    EntityTrait, IdenStatic, Iterable, Linked, PrimaryKeyToColumn, QuerySelect, Related,
+    RelationDef, Select, SelectA, SelectB, SelectTwo, SelectTwoMany, SelectWith,
 };
 pub use sea_query::JoinType;
-use sea_query::{Alias, Condition, Expr, IntoIden, SeaRc, SelectExpr};
+use sea_query::{
+    Alias, CommonTableExpression, Condition, DynIden, Expr, IntoIden, Query, SeaRc, SelectExpr,
+    SelectStatement, SimpleExpr, TableRef, UnionType, WithClause,
+};
 
 impl<E> Select<E>
 where
@@ -50,7 +54,8 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.left_join(r).select_also(r)
+        self.join_join(JoinType::LeftJoin, scope_related::<R>(E::to()), E::via())
+            .select_also(r)
     }
 
     /// Left Join with a Related Entity and select the related Entity as a `Vec`
@@ -59,7 +64,8 @@ where
         R: EntityTrait,
         E: Related<R>,
     {
-        self.left_join(r).select_with(r)
+        self.join_join(JoinType::LeftJoin, scope_related::<R>(E::to()), E::via())
+            .select_with(r)
     }
 
     /// Left Join with a Linked Entity and select both Entity.
@@ -107,11 +113,213 @@ where
         }
         select_two
     }
+
+    /// Filter the rows that have at least one related Entity matching the given query.
+    ///
+    /// The related query is turned into a correlated `EXISTS` subquery,
+    /// joining through the junction table of a many-to-many relation if needed.
+    pub fn filter_has<R>(mut self, f: impl FnOnce(Select<R>) -> Select<R>) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        self.query
+            .cond_where(Expr::exists(exists_related::<E, R>(f)));
+        self
+    }
+
+    /// Filter the rows that have no related Entity matching the given query.
+    pub fn filter_has_not<R>(mut self, f: impl FnOnce(Select<R>) -> Select<R>) -> Self
+    where
+        R: EntityTrait,
+        E: Related<R>,
+    {
+        self.query
+            .cond_where(Expr::expr(Expr::exists(exists_related::<E, R>(f))).not());
+        self
+    }
+
+    /// Filter the rows that have at least one Linked Entity matching the given query.
+    ///
+    /// A link chain may lead back to the same table it starts from,
+    /// so the linked query is matched against the primary key with `IN` instead of `EXISTS`.
+    pub fn filter_has_linked<L, T>(mut self, l: L, f: impl FnOnce(Select<T>) -> Select<T>) -> Self
+    where
+        L: Linked<FromEntity = E, ToEntity = T>,
+        T: EntityTrait,
+    {
+        let (expr, query) = in_linked(l, f);
+        self.query.cond_where(expr.in_subquery(query));
+        self
+    }
+
+    /// Filter the rows that have no Linked Entity matching the given query.
+    pub fn filter_has_not_linked<L, T>(
+        mut self,
+        l: L,
+        f: impl FnOnce(Select<T>) -> Select<T>,
+    ) -> Self
+    where
+        L: Linked<FromEntity = E, ToEntity = T>,
+        T: EntityTrait,
+    {
+        let (expr, query) = in_linked(l, f);
+        self.query.cond_where(expr.not_in_subquery(query));
+        self
+    }
+
+    /// Find all the Models reachable from the selected rows by following a self-referencing
+    /// link over and over again, e.g. all the ancestors or all the descendants of a node.
+    ///
+    /// This is done with a recursive common table expression. The selected rows themselves
+    /// are not part of the result, unless they can be reached from one another.
+    pub fn find_linked_recursive<L>(self, l: L) -> SelectWith<E>
+    where
+        L: Linked<FromEntity = E, ToEntity = E>,
+    {
+        let cte_name = Alias::new("linked_recursive").into_iden();
+        let seed_alias = Alias::new("linked_seed").into_iden();
+        let pk_cols: Vec<DynIden> = E::PrimaryKey::iter()
+            .map(|key| key.into_column().into_iden())
+            .collect();
+
+        let mut seed = self.query;
+        seed.clear_selects();
+        for key in E::PrimaryKey::iter() {
+            seed.expr(Expr::col(key.into_column().as_column_ref()));
+        }
+
+        let mut cte_query = linked_step(
+            &l,
+            TableRef::SubQuery(seed, SeaRc::clone(&seed_alias)),
+            seed_alias,
+        );
+        cte_query.union(
+            UnionType::Distinct,
+            linked_step(
+                &l,
+                TableRef::Table(SeaRc::clone(&cte_name)),
+                SeaRc::clone(&cte_name),
+            ),
+        );
+
+        let cte = CommonTableExpression::new()
+            .query(cte_query)
+            .columns(pk_cols.clone())
+            .table_name(SeaRc::clone(&cte_name))
+            .to_owned();
+        let with = WithClause::new().recursive(true).cte(cte).to_owned();
+
+        let mut select = E::find();
+        select.query.cond_where(
+            primary_key_expr::<E>()
+                .in_subquery(Query::select().columns(pk_cols).from(cte_name).to_owned()),
+        );
+        select.with(with)
+    }
+}
+
+/// Select the primary key of the linked Models, joining the rows they are linked from
+/// with the given table on its primary key columns.
+fn linked_step<L, E>(l: &L, tbl_ref: TableRef, tbl: DynIden) -> SelectStatement
+where
+    L: Linked<FromEntity = E, ToEntity = E>,
+    E: EntityTrait,
+{
+    let from_tbl = Alias::new(&format!("r{}", l.link().len() - 1)).into_iden();
+    let mut query = l.find_linked().query;
+    query.clear_selects();
+    let mut condition = Condition::all();
+    for key in E::PrimaryKey::iter() {
+        let col = key.into_column();
+        query.expr(Expr::col(col.as_column_ref()));
+        condition = condition.add(
+            Expr::tbl(SeaRc::clone(&tbl), col.into_iden())
+                .equals(SeaRc::clone(&from_tbl), col.into_iden()),
+        );
+    }
+    query.join(JoinType::InnerJoin, tbl_ref, condition);
+    query
+}
+
+fn primary_key_expr<E>() -> Expr
+where
+    E: EntityTrait,
+{
+    let mut cols: Vec<SimpleExpr> = E::PrimaryKey::iter()
+        .map(|key| Expr::col(key.into_column().as_column_ref()).into())
+        .collect();
+    if cols.len() == 1 {
+        Expr::expr(cols.remove(0))
+    } else {
+        Expr::tuple(cols)
+    }
+}
+
+fn exists_related<E, R>(f: impl FnOnce(Select<R>) -> Select<R>) -> SelectStatement
+where
+    E: EntityTrait + Related<R>,
+    R: EntityTrait,
+{
+    let mut select = f(R::find());
+    let condition = match E::via() {
+        Some(via) => {
+            select = select.join_rev(JoinType::InnerJoin, E::to());
+            join_condition(via)
+        }
+        None => join_condition(E::to()),
+    };
+    select
+        .query
+        .clear_selects()
+        .expr(Expr::cust("1"))
+        .cond_where(condition);
+    select.query
+}
+
+fn in_linked<L, E, T>(l: L, f: impl FnOnce(Select<T>) -> Select<T>) -> (Expr, SelectStatement)
+where
+    L: Linked<FromEntity = E, ToEntity = T>,
+    E: EntityTrait,
+    T: EntityTrait,
+{
+    let from_tbl = Alias::new(&format!("r{}", l.link().len() - 1)).into_iden();
+    let mut query = f(l.find_linked()).query;
+    query.clear_selects();
+    for key in E::PrimaryKey::iter() {
+        query.column((SeaRc::clone(&from_tbl), key.into_column().into_iden()));
+    }
+    (primary_key_expr::<E>(), query)
+}
+
+/// Add the default scope and the soft delete filter of the related Entity to the join condition
+fn scope_related<R>(mut rel: RelationDef) -> RelationDef
+where
+    R: EntityTrait,
+{
+    let mut scope = Condition::all();
+    if let Some(condition) = R::default_scope() {
+        scope = scope.add(condition);
+    }
+    if let Some(col) = R::soft_delete_column() {
+        scope = scope.add(col.is_null());
+    }
+    if scope.is_empty() {
+        return rel;
+    }
+    let on_condition = rel.on_condition.take();
+    rel.on_condition = Some(Box::new(move |left, right| match &on_condition {
+        Some(f) => scope.clone().add(f(left, right)),
+        None => scope.clone(),
+    }));
+    rel
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, cake_filling, cake_filling_price, entity_linked, filling, fruit};
+    use crate::tests_cfg::{
+        cake, cake_filling, cake_filling_price, cookie, entity_linked, filling, fruit, vendor,
+    };
     use crate::{
         ColumnTrait, DbBackend, EntityTrait, ModelTrait, QueryFilter, QuerySelect, QueryTrait,
         RelationTrait,
@@ -559,4 +767,200 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn join_22() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has::<fruit::Entity>(|q| q.filter(fruit::Column::Name.contains("cherry")))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE EXISTS(SELECT 1 FROM `fruit` WHERE `fruit`.`name` LIKE '%cherry%' AND `cake`.`id` = `fruit`.`cake_id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_23() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_not::<filling::Entity>(|q| q.filter(filling::Column::Name.eq("apple")))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE NOT EXISTS(SELECT 1 FROM `filling`",
+                "INNER JOIN `cake_filling` ON `cake_filling`.`filling_id` = `filling`.`id`",
+                "WHERE `filling`.`name` = 'apple' AND `cake`.`id` = `cake_filling`.`cake_id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_24() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_linked(entity_linked::CakeToFillingVendor, |q| {
+                    q.filter(vendor::Column::Name.eq("Vendor A"))
+                })
+                .filter_has_not_linked(entity_linked::CakeToFilling, |q| q)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE `cake`.`id` IN (SELECT `r2`.`id` FROM `vendor`",
+                "INNER JOIN `filling` AS `r0` ON `r0`.`vendor_id` = `vendor`.`id`",
+                "INNER JOIN `cake_filling` AS `r1` ON `r1`.`filling_id` = `r0`.`id`",
+                "INNER JOIN `cake` AS `r2` ON `r2`.`id` = `r1`.`cake_id`",
+                "WHERE `vendor`.`name` = 'Vendor A')",
+                "AND `cake`.`id` NOT IN (SELECT `r1`.`id` FROM `filling`",
+                "INNER JOIN `cake_filling` AS `r0` ON `r0`.`filling_id` = `filling`.`id`",
+                "INNER JOIN `cake` AS `r1` ON `r1`.`id` = `r0`.`cake_id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_25() {
+        let cake_model = cake::Model {
+            id: 12,
+            name: "".to_owned(),
+        };
+
+        assert_eq!(
+            cake_model
+                .find_related(cookie::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cookie`.`id`, `cookie`.`name`, `cookie`.`discontinued`, `cookie`.`cake_id` FROM `cookie`",
+                "INNER JOIN `cake` ON `cake`.`id` = `cookie`.`cake_id`",
+                "WHERE `cookie`.`discontinued` = FALSE AND `cake`.`id` = 12",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_26() {
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(cookie::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`cookie`.`id` AS `B_id`, `cookie`.`name` AS `B_name`, `cookie`.`discontinued` AS `B_discontinued`, `cookie`.`cake_id` AS `B_cake_id`",
+                "FROM `cake`",
+                "LEFT JOIN `cookie` ON `cake`.`id` = `cookie`.`cake_id` AND `cookie`.`discontinued` = FALSE",
+            ]
+            .join(" ")
+        );
+        assert_eq!(
+            cake::Entity::find()
+                .find_also_related(fruit::Entity)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id` AS `A_id`, `cake`.`name` AS `A_name`,",
+                "`fruit`.`id` AS `B_id`, `fruit`.`name` AS `B_name`, `fruit`.`cake_id` AS `B_cake_id`",
+                "FROM `cake`",
+                "LEFT JOIN `fruit` ON `cake`.`id` = `fruit`.`cake_id`",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn join_27() {
+        use crate::tests_cfg::recipe;
+
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has::<recipe::Entity>(|q| q.filter(recipe::Column::Name.contains("tart")))
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE EXISTS(SELECT 1 FROM `recipe` WHERE `recipe`.`deleted_at` IS NULL",
+                "AND `recipe`.`name` LIKE '%tart%' AND `cake`.`id` = `recipe`.`cake_id`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn join_28() {
+        use crate::tests_cfg::recipe;
+
+        assert_eq!(
+            recipe::Entity::find()
+                .filter(recipe::Column::Id.eq(1))
+                .find_linked_recursive(entity_linked::RecipeToSameCakeRecipe)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "WITH RECURSIVE `linked_recursive` (`id`) AS (SELECT `recipe`.`id` FROM `recipe`",
+                "INNER JOIN `cake` AS `r0` ON `r0`.`id` = `recipe`.`cake_id`",
+                "INNER JOIN `recipe` AS `r1` ON `r1`.`cake_id` = `r0`.`id`",
+                "INNER JOIN (SELECT `recipe`.`id` FROM `recipe` WHERE `recipe`.`deleted_at` IS NULL AND `recipe`.`id` = 1) AS `linked_seed`",
+                "ON `linked_seed`.`id` = `r1`.`id` WHERE `recipe`.`deleted_at` IS NULL",
+                "UNION SELECT `recipe`.`id` FROM `recipe`",
+                "INNER JOIN `cake` AS `r0` ON `r0`.`id` = `recipe`.`cake_id`",
+                "INNER JOIN `recipe` AS `r1` ON `r1`.`cake_id` = `r0`.`id`",
+                "INNER JOIN `linked_recursive` ON `linked_recursive`.`id` = `r1`.`id` WHERE `recipe`.`deleted_at` IS NULL)",
+                "SELECT `recipe`.`id`, `recipe`.`name`, `recipe`.`cake_id`, `recipe`.`updated_at`, `recipe`.`deleted_at` FROM `recipe`",
+                "WHERE `recipe`.`deleted_at` IS NULL AND `recipe`.`id` IN (SELECT `id` FROM `linked_recursive`)",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn join_29() {
+        assert_eq!(
+            cake::Entity::find()
+                .filter_has_not::<cookie::Entity>(|q| q)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "SELECT `cake`.`id`, `cake`.`name` FROM `cake`",
+                "WHERE NOT EXISTS(SELECT 1 FROM `cookie` WHERE `cookie`.`discontinued` = FALSE",
+                "AND `cake`.`id` = `cookie`.`cake_id`)",
+            ]
+            .join(" "),
+        );
+    }
+
+    #[test]
+    fn join_30() {
+        assert_eq!(
+            cookie::Entity::find()
+                .filter(cookie::Column::Id.eq(1))
+                .find_linked_recursive(entity_linked::CookieToSameCakeCookie)
+                .build(DbBackend::MySql)
+                .to_string(),
+            [
+                "WITH RECURSIVE `linked_recursive` (`id`) AS (SELECT `cookie`.`id` FROM `cookie`",
+                "INNER JOIN `cake` AS `r0` ON `r0`.`id` = `cookie`.`cake_id`",
+                "INNER JOIN `cookie` AS `r1` ON `r1`.`cake_id` = `r0`.`id`",
+                "INNER JOIN (SELECT `cookie`.`id` FROM `cookie` WHERE `cookie`.`discontinued` = FALSE AND `cookie`.`id` = 1) AS `linked_seed`",
+                "ON `linked_seed`.`id` = `r1`.`id` WHERE `cookie`.`discontinued` = FALSE",
+                "UNION SELECT `cookie`.`id` FROM `cookie`",
+                "INNER JOIN `cake` AS `r0` ON `r0`.`id` = `cookie`.`cake_id`",
+                "INNER JOIN `cookie` AS `r1` ON `r1`.`cake_id` = `r0`.`id`",
+                "INNER JOIN `linked_recursive` ON `linked_recursive`.`id` = `r1`.`id` WHERE `cookie`.`discontinued` = FALSE)",
+                "SELECT `cookie`.`id`, `cookie`.`name`, `cookie`.`discontinued`, `cookie`.`cake_id` FROM `cookie`",
+                "WHERE `cookie`.`discontinued` = FALSE AND `cookie`.`id` IN (SELECT `id` FROM `linked_recursive`)",
+            ]
+            .join(" ")
+        );
+    }
 }
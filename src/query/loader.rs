@@ -1,9 +1,13 @@
 use crate::{
-    ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, Identity, ModelTrait, QueryFilter,
-    Related, RelationType, Select,
+    build_select, no_tenant_join, qualify_schema, tenant_filter, unpack_table_ref, ColumnTrait,
+    Condition, ConnectionTrait, DbErr, EntityTrait, Identity, ModelTrait, QueryFilter, QueryResult,
+    QuerySelect, Related, RelationType, Select, TryGetable,
 };
 use async_trait::async_trait;
-use sea_query::{Expr, IntoColumnRef, SimpleExpr, ValueTuple};
+use sea_query::{
+    Alias, ColumnRef, DynIden, Expr, IntoColumnRef, JoinType, Order, OrderedStatement,
+    OverStatement, Query, SimpleExpr, Value, ValueTuple, WindowStatement,
+};
 use std::{collections::HashMap, str::FromStr};
 
 /// A trait for basic Dataloader
@@ -27,13 +31,239 @@ pub trait LoaderTrait {
         R: EntityTrait,
         R::Model: Send + Sync,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load has_many relations, keeping at most `limit` related models for each model.
+    /// The related models of each model are ranked by `order_by`, which is evaluated per model
+    /// with `ROW_NUMBER() OVER (PARTITION BY ...)`. Requires window function support,
+    /// i.e. MySQL 8.0, Postgres or SQLite 3.25.
+    async fn load_many_with_limit<R, C>(
+        &self,
+        stmt: Select<R>,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load many_to_many relations, `via` being the junction Entity
+    async fn load_many_to_many<R, V, C>(
+        &self,
+        stmt: Select<R>,
+        via: V,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to count the related models of each model
+    async fn load_count<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Vec<i64>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to compute an aggregate over the related models of each model,
+    /// e.g. `Expr::col(Column::Price).sum()`. `None` if a model has no related models.
+    async fn load_aggregate<R, T, C>(
+        &self,
+        stmt: Select<R>,
+        expr: SimpleExpr,
+        db: &C,
+    ) -> Result<Vec<Option<T>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        T: TryGetable + Clone + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+}
+
+/// The shape of nested loader results, e.g. the result of [LoaderTrait::load_many] or
+/// [LoaderTrait::load_one], holding models at any depth of `Vec` and `Option`
+pub trait NestedModels {
+    /// The model at the innermost level
+    type Model: ModelTrait;
+
+    /// The same shape holding a `T` in place of each model
+    type Nested<T>;
+
+    /// Collect references to the models, in order
+    fn collect_models<'a>(&'a self, models: &mut Vec<&'a Self::Model>);
+
+    /// Put the data loaded for the collected models back into the shape of `self`,
+    /// using the default value where a model is missing
+    fn regroup<T, I>(&self, data: &mut I) -> Self::Nested<T>
+    where
+        T: Default,
+        I: Iterator<Item = T>;
+}
+
+impl<M> NestedModels for M
+where
+    M: ModelTrait,
+{
+    type Model = M;
+
+    type Nested<T> = T;
+
+    fn collect_models<'a>(&'a self, models: &mut Vec<&'a M>) {
+        models.push(self);
+    }
+
+    fn regroup<T, I>(&self, data: &mut I) -> T
+    where
+        T: Default,
+        I: Iterator<Item = T>,
+    {
+        data.next().unwrap_or_default()
+    }
+}
+
+impl<M> NestedModels for Option<M>
+where
+    M: ModelTrait,
+{
+    type Model = M;
+
+    type Nested<T> = T;
+
+    fn collect_models<'a>(&'a self, models: &mut Vec<&'a M>) {
+        models.extend(self.iter());
+    }
+
+    fn regroup<T, I>(&self, data: &mut I) -> T
+    where
+        T: Default,
+        I: Iterator<Item = T>,
+    {
+        self.as_ref().and_then(|_| data.next()).unwrap_or_default()
+    }
+}
+
+impl<N> NestedModels for Vec<N>
+where
+    N: NestedModels,
+{
+    type Model = N::Model;
+
+    type Nested<T> = Vec<N::Nested<T>>;
+
+    fn collect_models<'a>(&'a self, models: &mut Vec<&'a N::Model>) {
+        for nested in self.iter() {
+            nested.collect_models(models);
+        }
+    }
+
+    fn regroup<T, I>(&self, data: &mut I) -> Vec<N::Nested<T>>
+    where
+        T: Default,
+        I: Iterator<Item = T>,
+    {
+        self.iter().map(|nested| nested.regroup(data)).collect()
+    }
+}
+
+/// A trait for Dataloader on nested results, e.g. the result of [LoaderTrait::load_many],
+/// at any depth: `Vec<Vec<M>>`, `Vec<Vec<Option<M>>>`, `Vec<Vec<Vec<M>>>` and so on.
+/// Each level of nesting is still loaded with a single query.
+#[async_trait]
+pub trait NestedLoaderTrait {
+    /// Source model
+    type Model: ModelTrait;
+
+    /// The same shape as `Self` holding a `T` in place of each model
+    type Nested<T>;
+
+    /// Used to eager load has_one relations
+    async fn load_one<R, C>(
+        &self,
+        stmt: Select<R>,
+        db: &C,
+    ) -> Result<Self::Nested<Option<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    ///  Used to eager load has_many relations
+    async fn load_many<R, C>(
+        &self,
+        stmt: Select<R>,
+        db: &C,
+    ) -> Result<Self::Nested<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load has_many relations, keeping at most `limit` related models for each model.
+    /// The related models of each model are ranked by `order_by`, which is evaluated per model
+    /// with `ROW_NUMBER() OVER (PARTITION BY ...)`. Requires window function support,
+    /// i.e. MySQL 8.0, Postgres or SQLite 3.25.
+    async fn load_many_with_limit<R, C>(
+        &self,
+        stmt: Select<R>,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Self::Nested<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to eager load many_to_many relations, `via` being the junction Entity
+    async fn load_many_to_many<R, V, C>(
+        &self,
+        stmt: Select<R>,
+        via: V,
+        db: &C,
+    ) -> Result<Self::Nested<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to count the related models of each model
+    async fn load_count<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Self::Nested<i64>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
+
+    /// Used to compute an aggregate over the related models of each model,
+    /// e.g. `Expr::col(Column::Price).sum()`. `None` if a model has no related models.
+    async fn load_aggregate<R, T, C>(
+        &self,
+        stmt: Select<R>,
+        expr: SimpleExpr,
+        db: &C,
+    ) -> Result<Self::Nested<Option<T>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        T: TryGetable + Clone + Send,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>;
 }
 
 #[async_trait]
 impl<M> LoaderTrait for Vec<M>
 where
-    M: ModelTrait,
-    Vec<M>: Sync,
+    M: ModelTrait + Sync,
 {
     type Model = M;
 
@@ -44,47 +274,111 @@ where
         R::Model: Send + Sync,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
+        let models: Vec<&M> = self.iter().collect();
 
-        // we verify that is has_one relation
-        match (rel_def).rel_type {
-            RelationType::HasOne => (),
-            RelationType::HasMany => {
-                return Err(DbErr::Type("Relation is HasMany instead of HasOne".into()))
-            }
-        }
+        load_one(&models, stmt, db).await
+    }
 
-        let keys: Vec<ValueTuple> = self
-            .iter()
-            .map(|model: &M| extract_key(&rel_def.from_col, model))
-            .collect();
+    async fn load_many<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().collect();
+
+        load_many(&models, stmt, Vec::new(), None, db).await
+    }
+
+    async fn load_many_with_limit<R, C>(
+        &self,
+        stmt: Select<R>,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().collect();
+
+        load_many(&models, stmt, order_by, Some(limit), db).await
+    }
+
+    async fn load_many_to_many<R, V, C>(
+        &self,
+        stmt: Select<R>,
+        via: V,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().collect();
 
-        let condition = prepare_condition::<<R as EntityTrait>::Model>(&rel_def.to_col, &keys);
+        load_many_to_many(&models, stmt, via, db).await
+    }
 
-        let stmt = <Select<R> as QueryFilter>::filter(stmt, condition);
+    async fn load_count<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Vec<i64>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().collect();
 
-        let data = stmt.all(db).await?;
+        load_count(&models, stmt, db).await
+    }
 
-        let hashmap: HashMap<String, <R as EntityTrait>::Model> = data.into_iter().fold(
-            HashMap::<String, <R as EntityTrait>::Model>::new(),
-            |mut acc: HashMap<String, <R as EntityTrait>::Model>,
-             value: <R as EntityTrait>::Model| {
-                {
-                    let key = extract_key(&rel_def.to_col, &value);
+    async fn load_aggregate<R, T, C>(
+        &self,
+        stmt: Select<R>,
+        expr: SimpleExpr,
+        db: &C,
+    ) -> Result<Vec<Option<T>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        T: TryGetable + Clone + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().collect();
 
-                    acc.insert(format!("{:?}", key), value);
-                }
+        load_aggregate(&models, stmt, expr, db).await
+    }
+}
 
-                acc
-            },
-        );
+#[async_trait]
+impl<M> LoaderTrait for Vec<Option<M>>
+where
+    M: ModelTrait + Sync,
+{
+    type Model = M;
 
-        let result: Vec<Option<<R as EntityTrait>::Model>> = keys
-            .iter()
-            .map(|key| hashmap.get(&format!("{:?}", key)).cloned())
-            .collect();
+    async fn load_one<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Vec<Option<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().flatten().collect();
 
-        Ok(result)
+        let mut data = load_one(&models, stmt, db).await?.into_iter();
+
+        Ok(self
+            .iter()
+            .map(|model: &Option<M>| model.as_ref().and_then(|_| data.next().flatten()))
+            .collect())
     }
 
     async fn load_many<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Vec<Vec<R::Model>>, DbErr>
@@ -94,117 +388,734 @@ where
         R::Model: Send + Sync,
         <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
     {
-        let rel_def = <<<Self as LoaderTrait>::Model as ModelTrait>::Entity as Related<R>>::to();
+        let models: Vec<&M> = self.iter().flatten().collect();
 
-        // we verify that is has_many relation
-        match (rel_def).rel_type {
-            RelationType::HasMany => (),
-            RelationType::HasOne => {
-                return Err(DbErr::Type("Relation is HasOne instead of HasMany".into()))
-            }
-        }
+        let mut data = load_many(&models, stmt, Vec::new(), None, db)
+            .await?
+            .into_iter();
 
-        let keys: Vec<ValueTuple> = self
+        Ok(self
             .iter()
-            .map(|model: &M| extract_key(&rel_def.from_col, model))
-            .collect();
+            .map(|model: &Option<M>| model.as_ref().and_then(|_| data.next()).unwrap_or_default())
+            .collect())
+    }
 
-        let condition = prepare_condition::<<R as EntityTrait>::Model>(&rel_def.to_col, &keys);
+    async fn load_many_with_limit<R, C>(
+        &self,
+        stmt: Select<R>,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().flatten().collect();
 
-        let stmt = <Select<R> as QueryFilter>::filter(stmt, condition);
+        let mut data = load_many(&models, stmt, order_by, Some(limit), db)
+            .await?
+            .into_iter();
 
-        let data = stmt.all(db).await?;
+        Ok(self
+            .iter()
+            .map(|model: &Option<M>| model.as_ref().and_then(|_| data.next()).unwrap_or_default())
+            .collect())
+    }
 
-        let mut hashmap: HashMap<String, Vec<<R as EntityTrait>::Model>> =
-            keys.iter()
-                .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
-                    acc.insert(format!("{:?}", key), Vec::new());
+    async fn load_many_to_many<R, V, C>(
+        &self,
+        stmt: Select<R>,
+        via: V,
+        db: &C,
+    ) -> Result<Vec<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().flatten().collect();
 
-                    acc
-                });
+        let mut data = load_many_to_many(&models, stmt, via, db).await?.into_iter();
 
-        data.into_iter()
-            .for_each(|value: <R as EntityTrait>::Model| {
-                let key = extract_key(&rel_def.to_col, &value);
+        Ok(self
+            .iter()
+            .map(|model: &Option<M>| model.as_ref().and_then(|_| data.next()).unwrap_or_default())
+            .collect())
+    }
 
-                let vec = hashmap
-                    .get_mut(&format!("{:?}", key))
-                    .expect("Failed at finding key on hashmap");
+    async fn load_count<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Vec<i64>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().flatten().collect();
 
-                vec.push(value);
-            });
+        let mut data = load_count(&models, stmt, db).await?.into_iter();
 
-        let result: Vec<Vec<R::Model>> = keys
+        Ok(self
             .iter()
-            .map(|key: &ValueTuple| {
-                hashmap
-                    .get(&format!("{:?}", key))
-                    .cloned()
-                    .unwrap_or_default()
-            })
-            .collect();
-
-        Ok(result)
+            .map(|model: &Option<M>| model.as_ref().and_then(|_| data.next()).unwrap_or_default())
+            .collect())
     }
-}
 
-fn extract_key<Model>(target_col: &Identity, model: &Model) -> ValueTuple
-where
-    Model: ModelTrait,
-{
-    match target_col {
-        Identity::Unary(a) => {
-            let column_a =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &a.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:1"));
-            ValueTuple::One(model.get(column_a))
-        }
-        Identity::Binary(a, b) => {
-            let column_a =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &a.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:2"));
-            let column_b =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &b.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column B:2"));
-            ValueTuple::Two(model.get(column_a), model.get(column_b))
-        }
-        Identity::Ternary(a, b, c) => {
-            let column_a =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &a.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:3"));
-            let column_b =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &b.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column B:3"));
-            let column_c =
-                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
-                    &c.to_string(),
-                )
-                .unwrap_or_else(|_| panic!("Failed at mapping string to column C:3"));
-            ValueTuple::Three(
-                model.get(column_a),
-                model.get(column_b),
-                model.get(column_c),
-            )
-        }
+    async fn load_aggregate<R, T, C>(
+        &self,
+        stmt: Select<R>,
+        expr: SimpleExpr,
+        db: &C,
+    ) -> Result<Vec<Option<T>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        T: TryGetable + Clone + Send,
+        <<Self as LoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let models: Vec<&M> = self.iter().flatten().collect();
+
+        let mut data = load_aggregate(&models, stmt, expr, db).await?.into_iter();
+
+        Ok(self
+            .iter()
+            .map(|model: &Option<M>| model.as_ref().and_then(|_| data.next().flatten()))
+            .collect())
     }
 }
 
-fn prepare_condition<M>(col: &Identity, keys: &[ValueTuple]) -> Condition
+#[async_trait]
+impl<N> NestedLoaderTrait for Vec<Vec<N>>
 where
-    M: ModelTrait,
+    N: NestedModels + Sync,
+    N::Model: Sync,
 {
-    match col {
-        Identity::Unary(column_a) => {
+    type Model = N::Model;
+
+    type Nested<T> = Vec<Vec<N::Nested<T>>>;
+
+    async fn load_one<R, C>(
+        &self,
+        stmt: Select<R>,
+        db: &C,
+    ) -> Result<Self::Nested<Option<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let mut models = Vec::new();
+        self.collect_models(&mut models);
+
+        let data = load_one(&models, stmt, db).await?;
+
+        Ok(self.regroup(&mut data.into_iter()))
+    }
+
+    async fn load_many<R, C>(
+        &self,
+        stmt: Select<R>,
+        db: &C,
+    ) -> Result<Self::Nested<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let mut models = Vec::new();
+        self.collect_models(&mut models);
+
+        let data = load_many(&models, stmt, Vec::new(), None, db).await?;
+
+        Ok(self.regroup(&mut data.into_iter()))
+    }
+
+    async fn load_many_with_limit<R, C>(
+        &self,
+        stmt: Select<R>,
+        order_by: Vec<(R::Column, Order)>,
+        limit: u64,
+        db: &C,
+    ) -> Result<Self::Nested<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let mut models = Vec::new();
+        self.collect_models(&mut models);
+
+        let data = load_many(&models, stmt, order_by, Some(limit), db).await?;
+
+        Ok(self.regroup(&mut data.into_iter()))
+    }
+
+    async fn load_many_to_many<R, V, C>(
+        &self,
+        stmt: Select<R>,
+        via: V,
+        db: &C,
+    ) -> Result<Self::Nested<Vec<R::Model>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        R::Model: Send + Sync,
+        V: EntityTrait,
+        V::Model: Send + Sync,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let mut models = Vec::new();
+        self.collect_models(&mut models);
+
+        let data = load_many_to_many(&models, stmt, via, db).await?;
+
+        Ok(self.regroup(&mut data.into_iter()))
+    }
+
+    async fn load_count<R, C>(&self, stmt: Select<R>, db: &C) -> Result<Self::Nested<i64>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let mut models = Vec::new();
+        self.collect_models(&mut models);
+
+        let data = load_count(&models, stmt, db).await?;
+
+        Ok(self.regroup(&mut data.into_iter()))
+    }
+
+    async fn load_aggregate<R, T, C>(
+        &self,
+        stmt: Select<R>,
+        expr: SimpleExpr,
+        db: &C,
+    ) -> Result<Self::Nested<Option<T>>, DbErr>
+    where
+        C: ConnectionTrait,
+        R: EntityTrait,
+        T: TryGetable + Clone + Send,
+        <<Self as NestedLoaderTrait>::Model as ModelTrait>::Entity: Related<R>,
+    {
+        let mut models = Vec::new();
+        self.collect_models(&mut models);
+
+        let data = load_aggregate(&models, stmt, expr, db).await?;
+
+        Ok(self.regroup(&mut data.into_iter()))
+    }
+}
+
+async fn load_one<M, R, C>(
+    models: &[&M],
+    stmt: Select<R>,
+    db: &C,
+) -> Result<Vec<Option<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    M: ModelTrait + Sync,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    <M as ModelTrait>::Entity: Related<R>,
+{
+    let rel_def = <<M as ModelTrait>::Entity as Related<R>>::to();
+
+    // we verify that is has_one relation
+    match (rel_def).rel_type {
+        RelationType::HasOne => (),
+        RelationType::HasMany => {
+            return Err(DbErr::Type("Relation is HasMany instead of HasOne".into()))
+        }
+    }
+
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &&M| extract_key(&rel_def.from_col, *model))
+        .collect();
+
+    let condition = prepare_condition::<<R as EntityTrait>::Model>(&rel_def.to_col, &keys);
+
+    let stmt = <Select<R> as QueryFilter>::filter(stmt, condition);
+
+    let data = stmt.all(db).await?;
+
+    let hashmap: HashMap<String, <R as EntityTrait>::Model> = data.into_iter().fold(
+        HashMap::<String, <R as EntityTrait>::Model>::new(),
+        |mut acc: HashMap<String, <R as EntityTrait>::Model>, value: <R as EntityTrait>::Model| {
+            {
+                let key = extract_key(&rel_def.to_col, &value);
+
+                acc.insert(format!("{:?}", key), value);
+            }
+
+            acc
+        },
+    );
+
+    let result: Vec<Option<<R as EntityTrait>::Model>> = keys
+        .iter()
+        .map(|key| hashmap.get(&format!("{:?}", key)).cloned())
+        .collect();
+
+    Ok(result)
+}
+
+async fn load_many<M, R, C>(
+    models: &[&M],
+    stmt: Select<R>,
+    order_by: Vec<(R::Column, Order)>,
+    limit: Option<u64>,
+    db: &C,
+) -> Result<Vec<Vec<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    M: ModelTrait + Sync,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    <M as ModelTrait>::Entity: Related<R>,
+{
+    let rel_def = <<M as ModelTrait>::Entity as Related<R>>::to();
+
+    // we verify that is has_many relation
+    match (rel_def).rel_type {
+        RelationType::HasMany => (),
+        RelationType::HasOne => {
+            return Err(DbErr::Type("Relation is HasOne instead of HasMany".into()))
+        }
+    }
+
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &&M| extract_key(&rel_def.from_col, *model))
+        .collect();
+
+    let condition = prepare_condition::<<R as EntityTrait>::Model>(&rel_def.to_col, &keys);
+
+    let stmt = <Select<R> as QueryFilter>::filter(stmt, condition);
+
+    let stmt = match limit {
+        Some(limit) => limit_per_partition(stmt, &rel_def.to_col, order_by, limit),
+        None => stmt,
+    };
+
+    let data = stmt.all(db).await?;
+
+    let mut hashmap: HashMap<String, Vec<<R as EntityTrait>::Model>> =
+        keys.iter()
+            .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
+                acc.insert(format!("{:?}", key), Vec::new());
+
+                acc
+            });
+
+    data.into_iter()
+        .for_each(|value: <R as EntityTrait>::Model| {
+            let key = extract_key(&rel_def.to_col, &value);
+
+            let vec = hashmap
+                .get_mut(&format!("{:?}", key))
+                .expect("Failed at finding key on hashmap");
+
+            vec.push(value);
+        });
+
+    let result: Vec<Vec<R::Model>> = keys
+        .iter()
+        .map(|key: &ValueTuple| {
+            hashmap
+                .get(&format!("{:?}", key))
+                .cloned()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    Ok(result)
+}
+
+async fn load_many_to_many<M, R, V, C>(
+    models: &[&M],
+    stmt: Select<R>,
+    via: V,
+    db: &C,
+) -> Result<Vec<Vec<R::Model>>, DbErr>
+where
+    C: ConnectionTrait,
+    M: ModelTrait + Sync,
+    R: EntityTrait,
+    R::Model: Send + Sync,
+    V: EntityTrait,
+    V::Model: Send + Sync,
+    <M as ModelTrait>::Entity: Related<R>,
+{
+    let via_def = match <<M as ModelTrait>::Entity as Related<R>>::via() {
+        Some(via_def) => via_def,
+        None => return Err(DbErr::Type("Relation is not ManyToMany".into())),
+    };
+    let rel_def = <<M as ModelTrait>::Entity as Related<R>>::to();
+
+    // we verify that the junction Entity matches the relation
+    if unpack_table_ref(&via_def.to_tbl).to_string()
+        != unpack_table_ref(&via.table_ref()).to_string()
+    {
+        return Err(DbErr::Type(format!(
+            "Junction Entity mismatch: expected {:?}, given {:?}",
+            via_def.to_tbl,
+            via.table_ref()
+        )));
+    }
+
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &&M| extract_key(&via_def.from_col, *model))
+        .collect();
+
+    let condition = prepare_condition::<<V as EntityTrait>::Model>(&via_def.to_col, &keys);
+
+    let junctions = V::find().filter(condition).all(db).await?;
+
+    // map of target key to the keys of the models it belongs to
+    let mut parents: HashMap<String, Vec<String>> = HashMap::new();
+    let mut target_keys: Vec<ValueTuple> = Vec::new();
+
+    junctions
+        .into_iter()
+        .for_each(|junction: <V as EntityTrait>::Model| {
+            let parent_key = extract_key(&via_def.to_col, &junction);
+            let target_key = extract_key(&rel_def.from_col, &junction);
+
+            let vec = parents
+                .entry(format!("{:?}", target_key))
+                .or_insert_with(|| {
+                    target_keys.push(target_key);
+                    Vec::new()
+                });
+
+            vec.push(format!("{:?}", parent_key));
+        });
+
+    let condition = prepare_condition::<<R as EntityTrait>::Model>(&rel_def.to_col, &target_keys);
+
+    let stmt = <Select<R> as QueryFilter>::filter(stmt, condition);
+
+    let data = stmt.all(db).await?;
+
+    let mut hashmap: HashMap<String, Vec<<R as EntityTrait>::Model>> =
+        keys.iter()
+            .fold(HashMap::new(), |mut acc, key: &ValueTuple| {
+                acc.insert(format!("{:?}", key), Vec::new());
+
+                acc
+            });
+
+    data.into_iter()
+        .for_each(|value: <R as EntityTrait>::Model| {
+            let key = extract_key(&rel_def.to_col, &value);
+
+            // junction rows may point at models that are not being loaded, these are skipped
+            let parent_keys = match parents.get(&format!("{:?}", key)) {
+                Some(parent_keys) => parent_keys,
+                None => return,
+            };
+
+            for parent_key in parent_keys {
+                if let Some(vec) = hashmap.get_mut(parent_key) {
+                    vec.push(value.clone());
+                }
+            }
+        });
+
+    let result: Vec<Vec<R::Model>> = keys
+        .iter()
+        .map(|key: &ValueTuple| {
+            hashmap
+                .get(&format!("{:?}", key))
+                .cloned()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    Ok(result)
+}
+
+async fn load_count<M, R, C>(models: &[&M], stmt: Select<R>, db: &C) -> Result<Vec<i64>, DbErr>
+where
+    C: ConnectionTrait,
+    M: ModelTrait + Sync,
+    R: EntityTrait,
+    <M as ModelTrait>::Entity: Related<R>,
+{
+    let data: Vec<Option<i64>> = load_aggregate(models, stmt, Expr::cust("COUNT(*)"), db).await?;
+
+    Ok(data.into_iter().map(Option::unwrap_or_default).collect())
+}
+
+async fn load_aggregate<M, R, T, C>(
+    models: &[&M],
+    stmt: Select<R>,
+    expr: SimpleExpr,
+    db: &C,
+) -> Result<Vec<Option<T>>, DbErr>
+where
+    C: ConnectionTrait,
+    M: ModelTrait + Sync,
+    R: EntityTrait,
+    T: TryGetable + Clone,
+    <M as ModelTrait>::Entity: Related<R>,
+{
+    let rel_def = <<M as ModelTrait>::Entity as Related<R>>::to();
+
+    // for many_to_many relations, the related models are grouped by the junction columns
+    let (stmt, key_tbl, key_col, from_col) = match <<M as ModelTrait>::Entity as Related<R>>::via()
+    {
+        Some(via_def) => (
+            stmt.join_rev(JoinType::InnerJoin, rel_def),
+            via_def.to_tbl,
+            via_def.to_col,
+            via_def.from_col,
+        ),
+        None => (stmt, rel_def.to_tbl, rel_def.to_col, rel_def.from_col),
+    };
+
+    let keys: Vec<ValueTuple> = models
+        .iter()
+        .map(|model: &&M| extract_key(&from_col, *model))
+        .collect();
+
+    let mut unique_keys: Vec<ValueTuple> = Vec::new();
+    let mut key_indices: HashMap<String, usize> = HashMap::new();
+    for key in keys.iter() {
+        key_indices.entry(format!("{:?}", key)).or_insert_with(|| {
+            unique_keys.push(key.clone());
+            unique_keys.len() - 1
+        });
+    }
+
+    if unique_keys.is_empty() {
+        return Ok(keys.iter().map(|_| None).collect());
+    }
+
+    let key_tbl = unpack_table_ref(&key_tbl);
+    let key_cols: Vec<DynIden> = match key_col {
+        Identity::Unary(a) => vec![a],
+        Identity::Binary(a, b) => vec![a, b],
+        Identity::Ternary(a, b, c) => vec![a, b, c],
+    };
+    let key_exprs: Vec<SimpleExpr> = key_cols
+        .iter()
+        .map(|col| Expr::tbl(key_tbl.clone(), col.clone()).into())
+        .collect();
+    let key_aliases: Vec<String> = (0..key_exprs.len()).map(|i| format!("key_{}", i)).collect();
+
+    let condition = match key_exprs.len() {
+        1 => Expr::expr(key_exprs[0].clone()).is_in(unique_keys.iter().cloned().flatten()),
+        _ => Expr::tuple(key_exprs.clone()).in_tuples(unique_keys.iter().cloned()),
+    };
+
+    let mut stmt = stmt.select_only().filter(condition);
+    if let Some(condition) = db.tenant().and_then(tenant_filter::<R>) {
+        stmt = stmt.filter(condition);
+    }
+    for (expr, alias) in key_exprs.iter().zip(key_aliases.iter()) {
+        stmt.query.expr_as(expr.clone(), Alias::new(alias));
+    }
+    stmt.query
+        .expr_as(expr, Alias::new("aggregate"))
+        .add_group_by(key_exprs)
+        .clear_order_by()
+        .reset_limit()
+        .reset_offset();
+
+    let rows = db
+        .query_all(build_select(
+            &stmt.query,
+            qualify_schema::<R>,
+            no_tenant_join,
+            db,
+        ))
+        .await?;
+
+    // the key columns are decoded as the same types as the keys of the models
+    let key_types: Vec<Value> = unique_keys[0].clone().into_iter().collect();
+    let mut data: Vec<Option<T>> = unique_keys.iter().map(|_| None).collect();
+    for row in rows {
+        let mut values = key_aliases
+            .iter()
+            .zip(key_types.iter())
+            .map(|(alias, like)| try_get_key(&row, alias, like))
+            .collect::<Result<Vec<Value>, DbErr>>()?
+            .into_iter();
+        let key = match (values.next(), values.next(), values.next()) {
+            (Some(a), None, None) => ValueTuple::One(a),
+            (Some(a), Some(b), None) => ValueTuple::Two(a, b),
+            (Some(a), Some(b), Some(c)) => ValueTuple::Three(a, b, c),
+            _ => unreachable!("a key has one to three columns"),
+        };
+        if let Some(index) = key_indices.get(&format!("{:?}", key)) {
+            data[*index] = row.try_get("", "aggregate")?;
+        }
+    }
+
+    let result: Vec<Option<T>> = keys
+        .iter()
+        .map(|key: &ValueTuple| data[key_indices[&format!("{:?}", key)]].clone())
+        .collect();
+
+    Ok(result)
+}
+
+/// Wrap the select statement such that at most `limit` rows are kept for each partition
+fn limit_per_partition<R>(
+    stmt: Select<R>,
+    partition_by: &Identity,
+    order_by: Vec<(R::Column, Order)>,
+    limit: u64,
+) -> Select<R>
+where
+    R: EntityTrait,
+{
+    let row_number = Alias::new("row_number_per_partition");
+
+    let partition_by: Vec<&DynIden> = match partition_by {
+        Identity::Unary(a) => vec![a],
+        Identity::Binary(a, b) => vec![a, b],
+        Identity::Ternary(a, b, c) => vec![a, b, c],
+    };
+
+    let mut window = WindowStatement::new();
+    for col in partition_by {
+        let col: R::Column = <R::Column as FromStr>::from_str(&col.to_string())
+            .unwrap_or_else(|_| panic!("Failed at mapping string to column *P"));
+        OverStatement::partition_by(&mut window, col.as_column_ref());
+    }
+    for (col, order) in order_by {
+        OrderedStatement::order_by(&mut window, col.as_column_ref(), order);
+    }
+
+    let mut query = stmt.query;
+    query.expr_window_as(Expr::cust("ROW_NUMBER()"), window, row_number.clone());
+
+    let mut wrapped = Query::select();
+    wrapped
+        .column(ColumnRef::Asterisk)
+        .from_subquery(query, Alias::new(R::default().table_name()))
+        .and_where(Expr::col(row_number.clone()).lte(limit))
+        .order_by(row_number, Order::Asc);
+
+    Select {
+        query: wrapped,
+        entity: stmt.entity,
+    }
+}
+
+fn extract_key<Model>(target_col: &Identity, model: &Model) -> ValueTuple
+where
+    Model: ModelTrait,
+{
+    match target_col {
+        Identity::Unary(a) => {
+            let column_a =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &a.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:1"));
+            ValueTuple::One(model.get(column_a))
+        }
+        Identity::Binary(a, b) => {
+            let column_a =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &a.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:2"));
+            let column_b =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &b.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column B:2"));
+            ValueTuple::Two(model.get(column_a), model.get(column_b))
+        }
+        Identity::Ternary(a, b, c) => {
+            let column_a =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &a.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column A:3"));
+            let column_b =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &b.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column B:3"));
+            let column_c =
+                <<<Model as ModelTrait>::Entity as EntityTrait>::Column as FromStr>::from_str(
+                    &c.to_string(),
+                )
+                .unwrap_or_else(|_| panic!("Failed at mapping string to column C:3"));
+            ValueTuple::Three(
+                model.get(column_a),
+                model.get(column_b),
+                model.get(column_c),
+            )
+        }
+    }
+}
+
+/// Read the key column `col` of a row, decoded as the same type as the key value `like`
+fn try_get_key(row: &QueryResult, col: &str, like: &Value) -> Result<Value, DbErr> {
+    macro_rules! get {
+        ($variant: ident, $type: ty) => {
+            Value::$variant(row.try_get::<Option<$type>>("", col)?)
+        };
+        (box $variant: ident, $type: ty) => {
+            Value::$variant(row.try_get::<Option<$type>>("", col)?.map(Box::new))
+        };
+    }
+
+    Ok(match like {
+        Value::Bool(_) => get!(Bool, bool),
+        Value::TinyInt(_) => get!(TinyInt, i8),
+        Value::SmallInt(_) => get!(SmallInt, i16),
+        Value::Int(_) => get!(Int, i32),
+        Value::BigInt(_) => get!(BigInt, i64),
+        Value::TinyUnsigned(_) => get!(TinyUnsigned, u8),
+        Value::SmallUnsigned(_) => get!(SmallUnsigned, u16),
+        Value::Unsigned(_) => get!(Unsigned, u32),
+        Value::BigUnsigned(_) => get!(BigUnsigned, u64),
+        Value::String(_) => get!(box String, String),
+        Value::Bytes(_) => get!(box Bytes, Vec<u8>),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDate(_) => get!(box ChronoDate, chrono::NaiveDate),
+        #[cfg(feature = "with-chrono")]
+        Value::ChronoDateTime(_) => get!(box ChronoDateTime, chrono::NaiveDateTime),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(_) => get!(box TimeDate, time::Date),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(_) => get!(box TimeDateTime, time::PrimitiveDateTime),
+        #[cfg(feature = "with-uuid")]
+        Value::Uuid(_) => get!(box Uuid, uuid::Uuid),
+        #[cfg(feature = "with-rust_decimal")]
+        Value::Decimal(_) => get!(box Decimal, rust_decimal::Decimal),
+        _ => {
+            return Err(DbErr::Type(format!(
+                "Aggregating by a key of {:?} is not supported",
+                like
+            )))
+        }
+    })
+}
+
+fn prepare_condition<M>(col: &Identity, keys: &[ValueTuple]) -> Condition
+where
+    M: ModelTrait,
+{
+    match col {
+        Identity::Unary(column_a) => {
             let column_a: <M::Entity as EntityTrait>::Column =
                 <<M::Entity as EntityTrait>::Column as FromStr>::from_str(&column_a.to_string())
                     .unwrap_or_else(|_| panic!("Failed at mapping string to column *A:1"));
@@ -336,4 +1247,517 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn test_load_many_to_many() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 1,
+                }
+                .into_mock_row(),
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 2,
+                }
+                .into_mock_row(),
+                cake_filling::Model {
+                    cake_id: 2,
+                    filling_id: 2,
+                }
+                .into_mock_row(),
+            ]])
+            .append_query_results(vec![vec![
+                filling::Model {
+                    id: 1,
+                    name: "Chocolate".to_owned(),
+                    vendor_id: None,
+                    ignored_attr: 0,
+                }
+                .into_mock_row(),
+                filling::Model {
+                    id: 2,
+                    name: "Cream".to_owned(),
+                    vendor_id: None,
+                    ignored_attr: 0,
+                }
+                .into_mock_row(),
+            ]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 3,
+                name: "Plain".to_owned(),
+            },
+        ];
+
+        let fillings = cakes
+            .load_many_to_many(filling::Entity::find(), cake_filling::Entity, &db)
+            .await
+            .expect("Should return something");
+
+        let chocolate = filling::Model {
+            id: 1,
+            name: "Chocolate".to_owned(),
+            vendor_id: None,
+            ignored_attr: 0,
+        };
+        let cream = filling::Model {
+            id: 2,
+            name: "Cream".to_owned(),
+            vendor_id: None,
+            ignored_attr: 0,
+        };
+
+        assert_eq!(
+            fillings,
+            vec![vec![chocolate, cream.clone()], vec![cream], vec![]]
+        );
+
+        assert!(cakes
+            .load_many_to_many(fruit::Entity::find(), cake_filling::Entity, &db)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_many_to_many_missing_parent() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                cake_filling::Model {
+                    cake_id: 1,
+                    filling_id: 1,
+                }
+                .into_mock_row(),
+                cake_filling::Model {
+                    cake_id: 4,
+                    filling_id: 2,
+                }
+                .into_mock_row(),
+            ]])
+            .append_query_results(vec![vec![
+                filling::Model {
+                    id: 1,
+                    name: "Chocolate".to_owned(),
+                    vendor_id: None,
+                    ignored_attr: 0,
+                }
+                .into_mock_row(),
+                filling::Model {
+                    id: 2,
+                    name: "Cream".to_owned(),
+                    vendor_id: None,
+                    ignored_attr: 0,
+                }
+                .into_mock_row(),
+                filling::Model {
+                    id: 3,
+                    name: "Jam".to_owned(),
+                    vendor_id: None,
+                    ignored_attr: 0,
+                }
+                .into_mock_row(),
+            ]])
+            .into_connection();
+
+        let cakes = vec![cake::Model {
+            id: 1,
+            name: "New York Cheese".to_owned(),
+        }];
+
+        let fillings = cakes
+            .load_many_to_many(filling::Entity::find(), cake_filling::Entity, &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fillings,
+            vec![vec![filling::Model {
+                id: 1,
+                name: "Chocolate".to_owned(),
+                vendor_id: None,
+                ignored_attr: 0,
+            }]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_one_optional() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            }
+            .into_mock_row()]])
+            .into_connection();
+
+        let fruits = vec![
+            None,
+            Some(fruit::Model {
+                id: 1,
+                name: "Apple".to_owned(),
+                cake_id: Some(1),
+            }),
+            Some(fruit::Model {
+                id: 2,
+                name: "Orange".to_owned(),
+                cake_id: None,
+            }),
+        ];
+
+        let cakes = fruits
+            .load_one(cake::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            cakes,
+            vec![
+                None,
+                Some(cake::Model {
+                    id: 1,
+                    name: "New York Cheese".to_owned(),
+                }),
+                None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_many_nested() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, MockDatabase,
+            NestedLoaderTrait,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit::Model {
+                    id: 1,
+                    name: "Apple".to_owned(),
+                    cake_id: Some(1),
+                }
+                .into_mock_row(),
+                fruit::Model {
+                    id: 2,
+                    name: "Orange".to_owned(),
+                    cake_id: Some(3),
+                }
+                .into_mock_row(),
+            ]])
+            .into_connection();
+
+        let cakes = vec![
+            vec![
+                cake::Model {
+                    id: 1,
+                    name: "New York Cheese".to_owned(),
+                },
+                cake::Model {
+                    id: 2,
+                    name: "London Cheese".to_owned(),
+                },
+            ],
+            vec![],
+            vec![cake::Model {
+                id: 3,
+                name: "Chocolate".to_owned(),
+            }],
+        ];
+
+        let fruits = cakes
+            .load_many(fruit::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            vec![
+                vec![
+                    vec![fruit::Model {
+                        id: 1,
+                        name: "Apple".to_owned(),
+                        cake_id: Some(1),
+                    }],
+                    vec![]
+                ],
+                vec![],
+                vec![vec![fruit::Model {
+                    id: 2,
+                    name: "Orange".to_owned(),
+                    cake_id: Some(3),
+                }]]
+            ]
+        );
+
+        assert_eq!(db.into_transaction_log().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_load_one_nested_deep() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, MockDatabase,
+            NestedLoaderTrait,
+        };
+
+        let cake = |id| cake::Model {
+            id,
+            name: "New York Cheese".to_owned(),
+        };
+        let fruit = |id, cake_id| fruit::Model {
+            id,
+            name: "Apple".to_owned(),
+            cake_id,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![
+                vec![cake(1).into_mock_row(), cake(2).into_mock_row()],
+                vec![cake(2).into_mock_row()],
+            ])
+            .into_connection();
+
+        let fruits = vec![
+            vec![vec![fruit(1, Some(1)), fruit(2, None)], vec![]],
+            vec![],
+            vec![vec![fruit(3, Some(2))]],
+        ];
+
+        let cakes = fruits
+            .load_one(cake::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            cakes,
+            vec![
+                vec![vec![Some(cake(1)), None], vec![]],
+                vec![],
+                vec![vec![Some(cake(2))]]
+            ]
+        );
+
+        let fruits = vec![vec![vec![None, Some(fruit(3, Some(2)))]], vec![vec![None]]];
+
+        let cakes = fruits
+            .load_one(cake::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            cakes,
+            vec![vec![vec![None, Some(cake(2))]], vec![vec![None]]]
+        );
+
+        assert_eq!(db.into_transaction_log().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_load_many_with_limit() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase,
+            Order, Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![
+                fruit::Model {
+                    id: 3,
+                    name: "Cherry".to_owned(),
+                    cake_id: Some(1),
+                }
+                .into_mock_row(),
+                fruit::Model {
+                    id: 2,
+                    name: "Banana".to_owned(),
+                    cake_id: Some(1),
+                }
+                .into_mock_row(),
+            ]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+        ];
+
+        let fruits = cakes
+            .load_many_with_limit(
+                fruit::Entity::find().filter(fruit::Column::Name.ne("Apple")),
+                vec![(fruit::Column::Id, Order::Desc)],
+                2,
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(
+            fruits,
+            vec![
+                vec![
+                    fruit::Model {
+                        id: 3,
+                        name: "Cherry".to_owned(),
+                        cake_id: Some(1),
+                    },
+                    fruit::Model {
+                        id: 2,
+                        name: "Banana".to_owned(),
+                        cake_id: Some(1),
+                    }
+                ],
+                vec![]
+            ]
+        );
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT * FROM (SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id","#,
+                    r#"ROW_NUMBER() OVER ( PARTITION BY "fruit"."cake_id" ORDER BY "fruit"."id" DESC ) AS "row_number_per_partition""#,
+                    r#"FROM "fruit" WHERE "fruit"."name" <> $1 AND "fruit"."cake_id" IN ($2, $3)) AS "fruit""#,
+                    r#"WHERE "row_number_per_partition" <= $4 ORDER BY "row_number_per_partition" ASC"#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec!["Apple".into(), 1i32.into(), 2i32.into(), 2u64.into()]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_count() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase,
+            Transaction,
+        };
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "key_0" => Into::<Value>::into(2),
+                "aggregate" => Into::<Value>::into(2i64),
+            }
+            .into_mock_row()]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+        ];
+
+        let counts = cakes
+            .load_count(fruit::Entity::find(), &db)
+            .await
+            .expect("Should return something");
+
+        assert_eq!(counts, vec![0, 2, 0]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "fruit"."cake_id" AS "key_0", COUNT(*) AS "aggregate" FROM "fruit""#,
+                    r#"WHERE "fruit"."cake_id" IN ($1, $2) GROUP BY "fruit"."cake_id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), 2i32.into()]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_aggregate_many_to_many() {
+        use crate::{
+            entity::prelude::*, tests_cfg::*, DbBackend, IntoMockRow, LoaderTrait, MockDatabase,
+            Transaction,
+        };
+        use sea_query::Expr;
+
+        let db = MockDatabase::new(DbBackend::Postgres)
+            .append_query_results(vec![vec![maplit::btreemap! {
+                "key_0" => Into::<Value>::into(1),
+                "aggregate" => Into::<Value>::into("Cream".to_owned()),
+            }
+            .into_mock_row()]])
+            .into_connection();
+
+        let cakes = vec![
+            cake::Model {
+                id: 1,
+                name: "New York Cheese".to_owned(),
+            },
+            cake::Model {
+                id: 2,
+                name: "London Cheese".to_owned(),
+            },
+        ];
+
+        let names: Vec<Option<String>> = cakes
+            .load_aggregate(
+                filling::Entity::find(),
+                Expr::tbl(filling::Entity, filling::Column::Name).max(),
+                &db,
+            )
+            .await
+            .expect("Should return something");
+
+        assert_eq!(names, vec![Some("Cream".to_owned()), None]);
+
+        assert_eq!(
+            db.into_transaction_log(),
+            vec![Transaction::from_sql_and_values(
+                DbBackend::Postgres,
+                [
+                    r#"SELECT "cake_filling"."cake_id" AS "key_0", MAX("filling"."name") AS "aggregate""#,
+                    r#"FROM "filling" INNER JOIN "cake_filling" ON "cake_filling"."filling_id" = "filling"."id""#,
+                    r#"WHERE "cake_filling"."cake_id" IN ($1, $2) GROUP BY "cake_filling"."cake_id""#,
+                ]
+                .join(" ")
+                .as_str(),
+                vec![1i32.into(), 2i32.into()]
+            )]
+        );
+    }
 }
@@ -1,11 +1,13 @@
 use crate::{
-    cast_enum_as_text, ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect,
-    QueryTrait,
+    cast_enum_as_text, ColumnTrait, DbBackend, EntityTrait, Iterable, QueryFilter, QueryOrder,
+    QuerySelect, QueryTrait, Statement,
 };
 use core::fmt::Debug;
 use core::marker::PhantomData;
 pub use sea_query::JoinType;
-use sea_query::{IntoColumnRef, SelectStatement, SimpleExpr};
+use sea_query::{
+    IntoColumnRef, QueryStatementBuilder, SelectStatement, SimpleExpr, WithClause, WithQuery,
+};
 
 /// Defines a structure to perform select operations
 #[derive(Clone, Debug)]
@@ -39,6 +41,17 @@ where
     pub(crate) entity: PhantomData<(E, F)>,
 }
 
+/// Defines a structure to perform select operations prefixed with a `WITH` clause
+#[derive(Clone, Debug)]
+pub struct SelectWith<E>
+where
+    E: EntityTrait,
+{
+    pub(crate) with: WithClause,
+    pub(crate) query: SelectStatement,
+    pub(crate) entity: PhantomData<E>,
+}
+
 /// Performs a conversion to [SimpleExpr]
 pub trait IntoSimpleExpr {
     /// Method to perform the conversion
@@ -88,6 +101,25 @@ impl_trait!(QuerySelect);
 impl_trait!(QueryFilter);
 impl_trait!(QueryOrder);
 
+macro_rules! impl_trait_with {
+    ( $trait: ident ) => {
+        impl<E> $trait for SelectWith<E>
+        where
+            E: EntityTrait,
+        {
+            type QueryStatement = SelectStatement;
+
+            fn query(&mut self) -> &mut SelectStatement {
+                &mut self.query
+            }
+        }
+    };
+}
+
+impl_trait_with!(QuerySelect);
+impl_trait_with!(QueryFilter);
+impl_trait_with!(QueryOrder);
+
 impl<C> IntoSimpleExpr for C
 where
     C: ColumnTrait,
@@ -131,6 +163,77 @@ where
         self.query.from(E::default().table_ref());
         self
     }
+
+    /// Attach a `WITH` clause to the query, so that its common table expressions
+    /// can be referenced by name in joins and filters.
+    ///
+    /// ```
+    /// use sea_orm::{
+    ///     entity::*,
+    ///     query::*,
+    ///     sea_query::{Alias, CommonTableExpression, Query, WithClause},
+    ///     tests_cfg::{cake, fruit},
+    ///     DbBackend,
+    /// };
+    ///
+    /// let cte = CommonTableExpression::new()
+    ///     .query(
+    ///         Query::select()
+    ///             .column(fruit::Column::CakeId)
+    ///             .from(fruit::Entity)
+    ///             .and_where(fruit::Column::Name.contains("cherry"))
+    ///             .to_owned(),
+    ///     )
+    ///     .table_name(Alias::new("cherry_cake"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     cake::Entity::find()
+    ///         .filter(
+    ///             cake::Column::Id.in_subquery(
+    ///                 Query::select()
+    ///                     .column(fruit::Column::CakeId)
+    ///                     .from(Alias::new("cherry_cake"))
+    ///                     .to_owned()
+    ///             )
+    ///         )
+    ///         .with(WithClause::new().cte(cte).to_owned())
+    ///         .build(DbBackend::Postgres)
+    ///         .to_string(),
+    ///     [
+    ///         r#"WITH "cherry_cake" AS (SELECT "cake_id" FROM "fruit" WHERE "fruit"."name" LIKE '%cherry%')"#,
+    ///         r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+    ///         r#"WHERE "cake"."id" IN (SELECT "cake_id" FROM "cherry_cake")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn with(self, with: WithClause) -> SelectWith<E> {
+        SelectWith {
+            with,
+            query: self.query,
+            entity: PhantomData,
+        }
+    }
+}
+
+impl<E> SelectWith<E>
+where
+    E: EntityTrait,
+{
+    /// Take ownership of the query builder, prefixed with the `WITH` clause
+    pub fn into_query(self) -> WithQuery {
+        self.with.query(self.query)
+    }
+
+    /// Build the query as [`Statement`]
+    pub fn build(&self, db_backend: DbBackend) -> Statement {
+        let query_builder = db_backend.get_query_builder();
+        Statement::from_string_values_tuple(
+            db_backend,
+            self.clone().into_query().build_any(query_builder.as_ref()),
+        )
+    }
 }
 
 impl<E> QueryTrait for Select<E>
@@ -1,9 +1,13 @@
 use crate::{
-    cast_text_as_enum, ActiveModelTrait, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    cast_text_as_enum, ActiveModelTrait, ColumnTrait, DbBackend, EntityName, EntityTrait,
+    IdenStatic, Iterable, PrimaryKeyToColumn, QueryFilter, QueryTrait, Statement,
 };
 use core::marker::PhantomData;
-use sea_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use sea_query::{
+    Alias, CaseStatement, Condition, ConditionHolder, DynIden, Expr, Iden, IntoCondition, IntoIden,
+    PostgresQueryBuilder, Query, QueryBuilder, QuotedBuilder, SeaRc, SimpleExpr, SqlWriter,
+    SqlWriterValues, TableRef, UpdateStatement,
+};
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -27,6 +31,22 @@ where
 {
     pub(crate) query: UpdateStatement,
     pub(crate) entity: PhantomData<E>,
+    /// The values and conditions of the query, from which it is rebuilt when the `updated_at`
+    /// column filled on construction is set explicitly
+    pub(crate) values: Vec<(DynIden, SimpleExpr)>,
+    pub(crate) condition: Condition,
+    pub(crate) updated_at_filled: bool,
+}
+
+/// Defines an UPDATE operation on multiple ActiveModels, each with its own values
+#[derive(Clone, Debug)]
+pub struct UpdateBatch<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) models: Vec<A>,
+    pub(crate) chunk_size: usize,
+    pub(crate) condition: Condition,
 }
 
 impl Update {
@@ -54,7 +74,7 @@ impl Update {
             query: UpdateStatement::new()
                 .table(A::Entity::default().table_ref())
                 .to_owned(),
-            model,
+            model: touch_updated_at(model),
         }
         .prepare_filters()
         .prepare_values()
@@ -81,6 +101,83 @@ impl Update {
         UpdateMany {
             query: UpdateStatement::new().table(entity.table_ref()).to_owned(),
             entity: PhantomData,
+            values: Vec::new(),
+            condition: Condition::all(),
+            updated_at_filled: false,
+        }
+        .prepare_updated_at()
+    }
+
+    /// Update many ActiveModel, each with its own values, using one statement per chunk
+    ///
+    /// Only the columns that are `Set` are updated, and every model must have its primary key set.
+    /// Models setting different columns are updated in separate statements.
+    ///
+    /// Postgres
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Update::batch(vec![
+    ///         cake::ActiveModel {
+    ///             id: ActiveValue::unchanged(1),
+    ///             name: ActiveValue::set("Apple Pie".to_owned()),
+    ///         },
+    ///         cake::ActiveModel {
+    ///             id: ActiveValue::unchanged(2),
+    ///             name: ActiveValue::set("Orange Scone".to_owned()),
+    ///         },
+    ///     ])
+    ///     .build(DbBackend::Postgres)
+    ///     .iter()
+    ///     .map(|stmt| stmt.to_string())
+    ///     .collect::<Vec<_>>(),
+    ///     vec![[
+    ///         r#"UPDATE "cake" SET "name" = "batch"."name""#,
+    ///         r#"FROM (VALUES (1, 'Apple Pie'), (2, 'Orange Scone')) AS "batch" ("id", "name")"#,
+    ///         r#"WHERE "cake"."id" = "batch"."id""#,
+    ///     ]
+    ///     .join(" ")],
+    /// );
+    /// ```
+    ///
+    /// MySQL
+    /// ```
+    /// use sea_orm::{entity::*, query::*, tests_cfg::cake, DbBackend};
+    ///
+    /// assert_eq!(
+    ///     Update::batch(vec![
+    ///         cake::ActiveModel {
+    ///             id: ActiveValue::unchanged(1),
+    ///             name: ActiveValue::set("Apple Pie".to_owned()),
+    ///         },
+    ///         cake::ActiveModel {
+    ///             id: ActiveValue::unchanged(2),
+    ///             name: ActiveValue::set("Orange Scone".to_owned()),
+    ///         },
+    ///     ])
+    ///     .build(DbBackend::MySql)
+    ///     .iter()
+    ///     .map(|stmt| stmt.to_string())
+    ///     .collect::<Vec<_>>(),
+    ///     vec![[
+    ///         r#"UPDATE `cake` SET `name` ="#,
+    ///         r#"(CASE WHEN (`cake`.`id` = 1) THEN 'Apple Pie' WHEN (`cake`.`id` = 2) THEN 'Orange Scone' ELSE `cake`.`name` END)"#,
+    ///         r#"WHERE `cake`.`id` IN (1, 2)"#,
+    ///     ]
+    ///     .join(" ")],
+    /// );
+    /// ```
+    pub fn batch<E, A, I>(models: I) -> UpdateBatch<A>
+    where
+        E: EntityTrait,
+        A: ActiveModelTrait<Entity = E>,
+        I: IntoIterator<Item = A>,
+    {
+        UpdateBatch {
+            models: models.into_iter().map(touch_updated_at).collect(),
+            chunk_size: UpdateBatch::<A>::DEFAULT_CHUNK_SIZE,
+            condition: Condition::all(),
         }
     }
 }
@@ -99,20 +196,33 @@ where
                 panic!("PrimaryKey is not set");
             }
         }
+        if let Some(col) = <A::Entity as EntityTrait>::version_column() {
+            let av = self.model.get(col);
+            if av.is_set() || av.is_unchanged() {
+                self = self.filter(col.eq(av.unwrap()));
+            }
+        }
         self
     }
 
     fn prepare_values(mut self) -> Self {
+        let version_column = <A::Entity as EntityTrait>::version_column();
         for col in <A::Entity as EntityTrait>::Column::iter() {
             if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
                 continue;
             }
+            if version_column.map(|v| v.as_str() == col.as_str()) == Some(true) {
+                continue;
+            }
             let av = self.model.get(col);
             if av.is_set() {
                 let expr = cast_text_as_enum(Expr::val(av.into_value().unwrap()), &col);
                 self.query.value(col, expr);
             }
         }
+        if let Some(col) = version_column {
+            self.query.value(col, Expr::col(col).add(1));
+        }
         self
     }
 }
@@ -137,6 +247,16 @@ where
     fn query(&mut self) -> &mut UpdateStatement {
         &mut self.query
     }
+
+    fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        let condition = filter.into_condition();
+        self.condition = self.condition.add(condition.clone());
+        self.query.cond_where(condition);
+        self
+    }
 }
 
 impl<A> QueryTrait for UpdateOne<A>
@@ -190,7 +310,7 @@ where
             let av = model.get(col);
             if av.is_set() {
                 let expr = cast_text_as_enum(Expr::val(av.into_value().unwrap()), &col);
-                self.query.value(col, expr);
+                self.value(col.into_iden(), expr);
             }
         }
         self
@@ -201,14 +321,313 @@ where
     where
         T: IntoIden,
     {
-        self.query.value(col, expr);
+        self.value(col.into_iden(), expr);
         self
     }
+
+    fn value(&mut self, col: DynIden, expr: SimpleExpr) {
+        let is_updated_at =
+            E::updated_at_column().map(|c| c.as_str() == col.to_string()) == Some(true);
+        self.values.push((col.clone(), expr.clone()));
+        if is_updated_at && self.updated_at_filled {
+            // the statement cannot drop a value, so it is rebuilt without the current time
+            let mut query = Query::update();
+            query
+                .table(E::default().table_ref())
+                .values(self.values.clone());
+            if !self.condition.is_empty() {
+                query.cond_where(self.condition.clone());
+            }
+            self.query = query;
+            self.updated_at_filled = false;
+        } else {
+            self.query.value(col, expr);
+        }
+    }
+
+    /// Set the `updated_at` column to the current time, until it is set explicitly
+    fn prepare_updated_at(mut self) -> Self {
+        if let Some(updated_at) = E::updated_at_column() {
+            if let Some(now) = updated_at.current_timestamp() {
+                self.query.value(updated_at, Expr::val(now));
+                self.updated_at_filled = true;
+            }
+        }
+        self
+    }
+}
+
+/// Set the `updated_at` column of the model to the current time, unless it was set explicitly
+fn touch_updated_at<A>(mut model: A) -> A
+where
+    A: ActiveModelTrait,
+{
+    if let Some(updated_at) = <A::Entity as EntityTrait>::updated_at_column() {
+        if !model.get(updated_at).is_set() {
+            if let Some(now) = updated_at.current_timestamp() {
+                model.set(updated_at, now);
+            }
+        }
+    }
+    model
+}
+
+impl<A> UpdateBatch<A>
+where
+    A: ActiveModelTrait,
+{
+    const DEFAULT_CHUNK_SIZE: usize = 1000;
+
+    /// Set the maximum number of models updated by a single statement, 1000 by default
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Only update the rows of the batch also matching the condition
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: IntoCondition,
+    {
+        self.condition = self.condition.add(filter.into_condition());
+        self
+    }
+
+    /// Build the UPDATE statements for the given database backend, one per chunk
+    pub fn build(&self, db_backend: DbBackend) -> Vec<Statement> {
+        self.build_for(db_backend, A::Entity::default().table_ref(), None)
+            .into_iter()
+            .map(|(statement, _)| statement)
+            .collect()
+    }
+
+    /// Build the UPDATE statements of the given table, restricting every statement
+    /// by an additional condition
+    ///
+    /// Each statement comes with the number of models it updates by matching their version,
+    /// which is the number of rows it must affect, or `None` if the models carry no version.
+    pub(crate) fn build_for(
+        &self,
+        db_backend: DbBackend,
+        table_ref: TableRef,
+        filter: Option<SimpleExpr>,
+    ) -> Vec<(Statement, Option<usize>)> {
+        let version_column = <A::Entity as EntityTrait>::version_column();
+        let mut condition = self.condition.clone();
+        if let Some(filter) = filter {
+            condition = condition.add(filter);
+        }
+        // group the models by the columns they set, keeping the order of first appearance
+        type Group<'a, A> = (
+            (Vec<bool>, bool),
+            Vec<<<A as ActiveModelTrait>::Entity as EntityTrait>::Column>,
+            Vec<&'a A>,
+        );
+        let mut groups: Vec<Group<A>> = Vec::new();
+        for model in self.models.iter() {
+            for key in <A::Entity as EntityTrait>::PrimaryKey::iter() {
+                let av = model.get(key.into_column());
+                if !(av.is_set() || av.is_unchanged()) {
+                    panic!("PrimaryKey is not set");
+                }
+            }
+            let mask: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+                .map(|col| {
+                    <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_none()
+                        && version_column.map(|v| v.as_str() == col.as_str()) != Some(true)
+                        && model.get(col).is_set()
+                })
+                .collect();
+            if !mask.contains(&true) {
+                continue;
+            }
+            let versioned = version_column
+                .map(|col| {
+                    let av = model.get(col);
+                    av.is_set() || av.is_unchanged()
+                })
+                .unwrap_or(false);
+            let mask = (mask, versioned);
+            match groups.iter_mut().find(|(m, _, _)| m == &mask) {
+                Some((_, _, models)) => models.push(model),
+                None => {
+                    let columns = <A::Entity as EntityTrait>::Column::iter()
+                        .zip(mask.0.iter())
+                        .filter_map(|(col, set)| if *set { Some(col) } else { None })
+                        .collect();
+                    groups.push((mask, columns, vec![model]))
+                }
+            }
+        }
+        let mut statements = Vec::new();
+        for ((_, versioned), columns, models) in groups.iter() {
+            let version = if *versioned { version_column } else { None };
+            for chunk in models.chunks(self.chunk_size) {
+                let statement = match db_backend {
+                    DbBackend::Postgres => Self::build_values_from(
+                        columns,
+                        version,
+                        chunk,
+                        table_ref.clone(),
+                        &condition,
+                    ),
+                    DbBackend::MySql | DbBackend::Sqlite => {
+                        let mut update = Self::build_case_when(columns, version, chunk);
+                        update.table(table_ref.clone());
+                        if !condition.is_empty() {
+                            update.cond_where(condition.clone());
+                        }
+                        db_backend.build(&update)
+                    }
+                };
+                statements.push((statement, version.map(|_| chunk.len())));
+            }
+        }
+        statements
+    }
+
+    /// `UPDATE .. SET col = batch.col FROM (VALUES ..) AS batch (pk, col) WHERE pk = batch.pk`
+    ///
+    /// With a version, `version = version + 1` is set and the rows are also matched on it.
+    fn build_values_from(
+        columns: &[<A::Entity as EntityTrait>::Column],
+        version: Option<<A::Entity as EntityTrait>::Column>,
+        models: &[&A],
+        table_ref: TableRef,
+        filter: &Condition,
+    ) -> Statement {
+        let entity = A::Entity::default();
+        let batch = Alias::new("batch").into_iden();
+        let keys: Vec<_> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .chain(version)
+            .collect();
+        let builder = PostgresQueryBuilder;
+        let quote = builder.quote();
+        let mut writer = SqlWriterValues::new("$", true);
+        let sql: &mut dyn SqlWriter = &mut writer;
+
+        write!(sql, "UPDATE ").unwrap();
+        builder.prepare_table_ref(&table_ref, sql);
+        write!(sql, " SET ").unwrap();
+        for (i, col) in columns.iter().enumerate() {
+            if i > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(sql.as_writer(), quote);
+            write!(sql, " = ").unwrap();
+            builder.prepare_simple_expr(
+                &cast_text_as_enum(Expr::col((SeaRc::clone(&batch), *col)), col),
+                sql,
+            );
+        }
+        if let Some(col) = version {
+            write!(sql, ", ").unwrap();
+            col.prepare(sql.as_writer(), quote);
+            write!(sql, " = ").unwrap();
+            builder.prepare_simple_expr(&Expr::col((entity, col)).add(1), sql);
+        }
+
+        write!(sql, " FROM (VALUES ").unwrap();
+        for (i, model) in models.iter().enumerate() {
+            if i > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            write!(sql, "(").unwrap();
+            for (j, col) in keys.iter().chain(columns.iter()).enumerate() {
+                if j > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                builder.prepare_value(&model.get(*col).into_value().unwrap(), sql);
+            }
+            write!(sql, ")").unwrap();
+        }
+        write!(sql, ") AS ").unwrap();
+        batch.prepare(sql.as_writer(), quote);
+        write!(sql, " (").unwrap();
+        for (i, col) in keys.iter().chain(columns.iter()).enumerate() {
+            if i > 0 {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(sql.as_writer(), quote);
+        }
+        write!(sql, ")").unwrap();
+
+        // the rows of the batch are matched on the primary key, and the version if any
+        let mut condition = keys.iter().fold(Condition::all(), |cond, key| {
+            cond.add(Expr::col((entity, *key)).eq(cast_text_as_enum(
+                Expr::col((SeaRc::clone(&batch), *key)),
+                key,
+            )))
+        });
+        if !filter.is_empty() {
+            condition = condition.add(filter.clone());
+        }
+        builder.prepare_condition(
+            &ConditionHolder::new_with_condition(condition),
+            "WHERE",
+            sql,
+        );
+
+        Statement::from_string_values_tuple(DbBackend::Postgres, writer.into_parts())
+    }
+
+    /// `UPDATE .. SET col = CASE WHEN pk = .. THEN .. ELSE col END WHERE pk IN (..)`
+    ///
+    /// With a version, `version = version + 1` is set and the rows are also matched on it.
+    fn build_case_when(
+        columns: &[<A::Entity as EntityTrait>::Column],
+        version: Option<<A::Entity as EntityTrait>::Column>,
+        models: &[&A],
+    ) -> UpdateStatement {
+        let entity = A::Entity::default();
+        let keys: Vec<_> = <A::Entity as EntityTrait>::PrimaryKey::iter()
+            .map(|key| key.into_column())
+            .chain(version)
+            .collect();
+        let key_condition = |model: &A| {
+            keys.iter().fold(Condition::all(), |cond, key| {
+                cond.add(Expr::col((entity, *key)).eq(model.get(*key).into_value().unwrap()))
+            })
+        };
+
+        let mut update = UpdateStatement::new().table(entity.table_ref()).to_owned();
+        for col in columns.iter() {
+            let case = models
+                .iter()
+                .fold(CaseStatement::new(), |case, model| {
+                    case.case(
+                        key_condition(model),
+                        cast_text_as_enum(Expr::val(model.get(*col).into_value().unwrap()), col),
+                    )
+                })
+                .finally(Expr::col((entity, *col)));
+            update.value(*col, case);
+        }
+        if let Some(col) = version {
+            update.value(col, Expr::col(col).add(1));
+        }
+        if let [key] = keys.as_slice() {
+            update.and_where(
+                Expr::col((entity, *key)).is_in(
+                    models
+                        .iter()
+                        .map(|model| model.get(*key).into_value().unwrap()),
+                ),
+            );
+        } else {
+            update.cond_where(models.iter().fold(Condition::any(), |cond, model| {
+                cond.add(key_condition(model))
+            }));
+        }
+        update
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::tests_cfg::{cake, fruit, lunch_set, sea_orm_active_enums::Tea};
+    use crate::tests_cfg::{cake, fruit, ingredient, lunch_set, sea_orm_active_enums::Tea};
     use crate::{entity::*, query::*, DbBackend};
     use sea_query::{Expr, Value};
 
@@ -324,4 +743,231 @@ mod tests {
             r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."id" = 1"#,
         );
     }
+
+    #[test]
+    fn update_9() {
+        assert_eq!(
+            Update::batch(vec![
+                lunch_set::ActiveModel {
+                    id: Unchanged(1),
+                    name: Set("Lunch".to_owned()),
+                    tea: Set(Tea::EverydayTea),
+                },
+                lunch_set::ActiveModel {
+                    id: Unchanged(2),
+                    tea: Set(Tea::BreakfastTea),
+                    ..Default::default()
+                },
+                lunch_set::ActiveModel {
+                    id: Unchanged(3),
+                    name: Unchanged("Brunch".to_owned()),
+                    tea: NotSet,
+                },
+                lunch_set::ActiveModel {
+                    id: Unchanged(4),
+                    name: Set("Dinner".to_owned()),
+                    tea: Set(Tea::BreakfastTea),
+                },
+            ])
+            .build(DbBackend::Postgres)
+            .iter()
+            .map(|stmt| stmt.to_string())
+            .collect::<Vec<_>>(),
+            vec![
+                [
+                    r#"UPDATE "lunch_set" SET "name" = "batch"."name", "tea" = CAST("batch"."tea" AS tea)"#,
+                    r#"FROM (VALUES (1, 'Lunch', 'EverydayTea'), (4, 'Dinner', 'BreakfastTea')) AS "batch" ("id", "name", "tea")"#,
+                    r#"WHERE "lunch_set"."id" = "batch"."id""#,
+                ]
+                .join(" "),
+                [
+                    r#"UPDATE "lunch_set" SET "tea" = CAST("batch"."tea" AS tea)"#,
+                    r#"FROM (VALUES (2, 'BreakfastTea')) AS "batch" ("id", "tea")"#,
+                    r#"WHERE "lunch_set"."id" = "batch"."id""#,
+                ]
+                .join(" "),
+            ],
+        );
+    }
+
+    #[test]
+    fn update_10() {
+        assert_eq!(
+            Update::batch((1..=3).map(|id| fruit::ActiveModel {
+                id: Unchanged(id),
+                cake_id: Set(Some(id + 10)),
+                ..Default::default()
+            }))
+            .chunk_size(2)
+            .build(DbBackend::Sqlite)
+            .iter()
+            .map(|stmt| stmt.to_string())
+            .collect::<Vec<_>>(),
+            vec![
+                [
+                    r#"UPDATE "fruit" SET "cake_id" ="#,
+                    r#"(CASE WHEN ("fruit"."id" = 1) THEN 11 WHEN ("fruit"."id" = 2) THEN 12 ELSE "fruit"."cake_id" END)"#,
+                    r#"WHERE "fruit"."id" IN (1, 2)"#,
+                ]
+                .join(" "),
+                [
+                    r#"UPDATE "fruit" SET "cake_id" ="#,
+                    r#"(CASE WHEN ("fruit"."id" = 3) THEN 13 ELSE "fruit"."cake_id" END)"#,
+                    r#"WHERE "fruit"."id" IN (3)"#,
+                ]
+                .join(" "),
+            ],
+        );
+    }
+
+    #[test]
+    fn update_11() {
+        assert_eq!(
+            Update::one(ingredient::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::set("Flour".to_owned()),
+                version: ActiveValue::unchanged(3),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "ingredient" SET "name" = 'Flour', "version" = "version" + 1 WHERE "ingredient"."id" = 1 AND "ingredient"."version" = 3"#,
+        );
+        assert_eq!(
+            Update::one(ingredient::ActiveModel {
+                id: ActiveValue::unchanged(1),
+                name: ActiveValue::set("Flour".to_owned()),
+                version: ActiveValue::not_set(),
+            })
+            .build(DbBackend::Postgres)
+            .to_string(),
+            r#"UPDATE "ingredient" SET "name" = 'Flour', "version" = "version" + 1 WHERE "ingredient"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn update_12() {
+        use crate::tests_cfg::recipe;
+
+        let sql = Update::many(recipe::Entity)
+            .col_expr(recipe::Column::Name, Expr::value("Tart"))
+            .filter(recipe::Column::Id.eq(1))
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert!(sql.starts_with(r#"UPDATE "recipe" SET "updated_at" = '"#));
+        assert!(sql.ends_with(r#"', "name" = 'Tart' WHERE "recipe"."id" = 1"#));
+
+        assert_eq!(
+            Update::many(recipe::Entity)
+                .filter(recipe::Column::Id.eq(1))
+                .col_expr(recipe::Column::Name, Expr::value("Tart"))
+                .set(recipe::ActiveModel {
+                    updated_at: ActiveValue::set(None),
+                    ..Default::default()
+                })
+                .build(DbBackend::Postgres)
+                .to_string(),
+            r#"UPDATE "recipe" SET "name" = 'Tart', "updated_at" = NULL WHERE "recipe"."id" = 1"#,
+        );
+    }
+
+    #[test]
+    fn update_13() {
+        use crate::tests_cfg::cookie;
+
+        let models = || {
+            (1..=2).map(|id| cookie::ActiveModel {
+                id: Unchanged(id),
+                name: Set(format!("Cookie {}", id)),
+                ..Default::default()
+            })
+        };
+        assert_eq!(
+            cookie::Entity::update_batch(models())
+                .build(DbBackend::Postgres)
+                .iter()
+                .map(|stmt| stmt.to_string())
+                .collect::<Vec<_>>(),
+            vec![[
+                r#"UPDATE "cookie" SET "name" = "batch"."name""#,
+                r#"FROM (VALUES (1, 'Cookie 1'), (2, 'Cookie 2')) AS "batch" ("id", "name")"#,
+                r#"WHERE "cookie"."id" = "batch"."id" AND "cookie"."discontinued" = FALSE"#,
+            ]
+            .join(" ")],
+        );
+        assert_eq!(
+            cookie::Entity::update_batch(models())
+                .build(DbBackend::MySql)
+                .iter()
+                .map(|stmt| stmt.to_string())
+                .collect::<Vec<_>>(),
+            vec![[
+                r#"UPDATE `cookie` SET `name` ="#,
+                r#"(CASE WHEN (`cookie`.`id` = 1) THEN 'Cookie 1' WHEN (`cookie`.`id` = 2) THEN 'Cookie 2' ELSE `cookie`.`name` END)"#,
+                r#"WHERE `cookie`.`id` IN (1, 2) AND `cookie`.`discontinued` = FALSE"#,
+            ]
+            .join(" ")],
+        );
+    }
+
+    #[test]
+    fn update_14() {
+        let models = || {
+            vec![
+                ingredient::ActiveModel {
+                    id: Unchanged(1),
+                    name: Set("Flour".to_owned()),
+                    version: Unchanged(3),
+                },
+                ingredient::ActiveModel {
+                    id: Unchanged(2),
+                    name: Set("Sugar".to_owned()),
+                    version: NotSet,
+                },
+            ]
+        };
+        assert_eq!(
+            Update::batch(models())
+                .build(DbBackend::Postgres)
+                .iter()
+                .map(|stmt| stmt.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                [
+                    r#"UPDATE "ingredient" SET "name" = "batch"."name", "version" = "ingredient"."version" + 1"#,
+                    r#"FROM (VALUES (1, 3, 'Flour')) AS "batch" ("id", "version", "name")"#,
+                    r#"WHERE "ingredient"."id" = "batch"."id" AND "ingredient"."version" = "batch"."version""#,
+                ]
+                .join(" "),
+                [
+                    r#"UPDATE "ingredient" SET "name" = "batch"."name""#,
+                    r#"FROM (VALUES (2, 'Sugar')) AS "batch" ("id", "name")"#,
+                    r#"WHERE "ingredient"."id" = "batch"."id""#,
+                ]
+                .join(" "),
+            ],
+        );
+        assert_eq!(
+            Update::batch(models())
+                .build(DbBackend::MySql)
+                .iter()
+                .map(|stmt| stmt.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                [
+                    r#"UPDATE `ingredient` SET `name` ="#,
+                    r#"(CASE WHEN (`ingredient`.`id` = 1 AND `ingredient`.`version` = 3) THEN 'Flour' ELSE `ingredient`.`name` END),"#,
+                    r#"`version` = `version` + 1"#,
+                    r#"WHERE `ingredient`.`id` = 1 AND `ingredient`.`version` = 3"#,
+                ]
+                .join(" "),
+                [
+                    r#"UPDATE `ingredient` SET `name` ="#,
+                    r#"(CASE WHEN (`ingredient`.`id` = 2) THEN 'Sugar' ELSE `ingredient`.`name` END)"#,
+                    r#"WHERE `ingredient`.`id` IN (2)"#,
+                ]
+                .join(" "),
+            ],
+        );
+    }
 }
@@ -1,10 +1,11 @@
 use crate::{
-    ActiveEnum, ColumnTrait, ColumnType, DbBackend, EntityTrait, Iterable, PrimaryKeyToColumn,
-    PrimaryKeyTrait, RelationTrait, Schema,
+    qualify_table_ref, unpack_table_ref, ActiveEnum, ColumnTrait, ColumnType, DbBackend,
+    EntityTrait, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, RelationTrait, Schema,
 };
 use sea_query::{
     extension::postgres::{Type, TypeCreateStatement},
-    ColumnDef, Iden, Index, IndexCreateStatement, TableCreateStatement,
+    ColumnDef, ForeignKeyCreateStatement, Iden, Index, IndexCreateStatement, IntoTableRef,
+    TableCreateStatement, TableRef,
 };
 
 impl Schema {
@@ -29,7 +30,7 @@ impl Schema {
     where
         E: EntityTrait,
     {
-        create_table_from_entity(entity, self.backend)
+        create_table_from_entity(entity, self.backend, self.schema_name.as_deref())
     }
 
     /// Creates the indexes from an Entity, returning an empty Vec if there are none
@@ -38,7 +39,7 @@ impl Schema {
     where
         E: EntityTrait,
     {
-        create_index_from_entity(entity, self.backend)
+        create_index_from_entity(entity, self.backend, self.schema_name.as_deref())
     }
 
     /// Creates a column definition for example to update a table.
@@ -131,6 +132,7 @@ where
 pub(crate) fn create_index_from_entity<E>(
     entity: E,
     _backend: DbBackend,
+    schema: Option<&str>,
 ) -> Vec<IndexCreateStatement>
 where
     E: EntityTrait,
@@ -147,7 +149,7 @@ where
                 entity.to_string(),
                 column.to_string()
             ))
-            .table(entity)
+            .table(table_ref_in_schema(entity.into_table_ref(), schema))
             .col(column)
             .to_owned();
         vec.push(stmt)
@@ -155,7 +157,11 @@ where
     vec
 }
 
-pub(crate) fn create_table_from_entity<E>(entity: E, backend: DbBackend) -> TableCreateStatement
+pub(crate) fn create_table_from_entity<E>(
+    entity: E,
+    backend: DbBackend,
+    schema: Option<&str>,
+) -> TableCreateStatement
 where
     E: EntityTrait,
 {
@@ -179,10 +185,29 @@ where
         if relation.is_owner {
             continue;
         }
-        stmt.foreign_key(&mut relation.into());
+        let to_tbl = relation.to_tbl.clone();
+        let mut foreign_key: ForeignKeyCreateStatement = relation.into();
+        if let Some(schema) = schema {
+            // MySQL and SQLite can only reference a table of the same schema
+            match backend {
+                DbBackend::Postgres => foreign_key.to_tbl(qualify_table_ref(&to_tbl, schema)),
+                DbBackend::MySql | DbBackend::Sqlite => {
+                    foreign_key.to_tbl(unpack_table_ref(&to_tbl))
+                }
+            };
+        }
+        stmt.foreign_key(&mut foreign_key);
     }
 
-    stmt.table(entity.table_ref()).take()
+    stmt.table(table_ref_in_schema(entity.table_ref(), schema))
+        .take()
+}
+
+fn table_ref_in_schema(table_ref: TableRef, schema: Option<&str>) -> TableRef {
+    match schema {
+        Some(schema) => qualify_table_ref(&table_ref, schema),
+        None => table_ref,
+    }
 }
 
 fn column_def_from_entity_column<E>(column: E::Column, backend: DbBackend) -> ColumnDef
@@ -245,7 +270,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_table_from_entity_with_schema() {
+        for builder in [DbBackend::MySql, DbBackend::Postgres, DbBackend::Sqlite] {
+            let schema = Schema::new(builder).with_schema("tenant_a");
+            let to_tbl = match builder {
+                DbBackend::Postgres => (Alias::new("tenant_a"), CakeFilling).into_table_ref(),
+                DbBackend::MySql | DbBackend::Sqlite => CakeFilling.into_table_ref(),
+            };
+            assert_eq!(
+                builder.build(&schema.create_table_from_entity(CakeFillingPrice)),
+                builder.build(
+                    &cake_filling_price_stmt(to_tbl)
+                        .table((Alias::new("tenant_a"), CakeFillingPrice))
+                        .to_owned()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_index_from_entity_with_schema() {
+        let builder = DbBackend::Postgres;
+        let schema = Schema::new(builder).with_schema("tenant_a");
+
+        let stmts = schema.create_index_from_entity(indexes::Entity);
+        let idx: IndexCreateStatement = Index::create()
+            .name("idx-indexes-index1_attr")
+            .table((Alias::new("tenant_a"), indexes::Entity))
+            .col(indexes::Column::Index1Attr)
+            .to_owned();
+        assert_eq!(builder.build(&stmts[0]), builder.build(&idx));
+    }
+
     fn get_cake_filling_price_stmt() -> TableCreateStatement {
+        cake_filling_price_stmt(CakeFilling.into_table_ref())
+    }
+
+    fn cake_filling_price_stmt(to_tbl: TableRef) -> TableCreateStatement {
         Table::create()
             .col(
                 ColumnDef::new(cake_filling_price::Column::CakeId)
@@ -275,7 +337,7 @@ mod tests {
                     .from_tbl(CakeFillingPrice)
                     .from_col(cake_filling_price::Column::CakeId)
                     .from_col(cake_filling_price::Column::FillingId)
-                    .to_tbl(CakeFilling)
+                    .to_tbl(to_tbl)
                     .to_col(cake_filling::Column::CakeId)
                     .to_col(cake_filling::Column::FillingId),
             )
@@ -7,11 +7,25 @@ mod entity;
 #[derive(Debug)]
 pub struct Schema {
     backend: DbBackend,
+    schema_name: Option<String>,
 }
 
 impl Schema {
     /// Create a helper for a specific database backend
     pub fn new(backend: DbBackend) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            schema_name: None,
+        }
+    }
+
+    /// Create the tables and indexes in the given schema, instead of the one given by
+    /// `#[sea_orm(schema_name = "..")]`, along with the tables referenced by foreign keys
+    ///
+    /// MySQL and SQLite reference the tables of foreign keys in the schema of the table,
+    /// and only Postgres supports creating indexes on a table in another schema.
+    pub fn with_schema(mut self, schema: &str) -> Self {
+        self.schema_name = Some(schema.to_owned());
+        self
     }
 }
@@ -31,6 +31,19 @@ impl Related<super::fruit::Entity> for Entity {
     }
 }
 
+impl Related<super::cookie::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::cookie::Relation::Cake.def().rev()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl Related<super::recipe::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::recipe::Relation::Cake.def().rev()
+    }
+}
+
 impl Related<super::filling::Entity> for Entity {
     fn to() -> RelationDef {
         super::cake_filling::Relation::Filling.def()
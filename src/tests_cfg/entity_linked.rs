@@ -80,3 +80,37 @@ impl Linked for JoinWithoutReverse {
         ]
     }
 }
+
+#[cfg(feature = "with-chrono")]
+#[derive(Debug)]
+pub struct RecipeToSameCakeRecipe;
+
+#[cfg(feature = "with-chrono")]
+impl Linked for RecipeToSameCakeRecipe {
+    type FromEntity = super::recipe::Entity;
+
+    type ToEntity = super::recipe::Entity;
+
+    fn link(&self) -> Vec<RelationDef> {
+        vec![
+            super::recipe::Relation::Cake.def(),
+            super::recipe::Relation::Cake.def().rev(),
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub struct CookieToSameCakeCookie;
+
+impl Linked for CookieToSameCakeCookie {
+    type FromEntity = super::cookie::Entity;
+
+    type ToEntity = super::cookie::Entity;
+
+    fn link(&self) -> Vec<RelationDef> {
+        vec![
+            super::cookie::Relation::Cake.def(),
+            super::cookie::Relation::Cake.def().rev(),
+        ]
+    }
+}
@@ -4,11 +4,17 @@ pub mod cake;
 pub mod cake_expanded;
 pub mod cake_filling;
 pub mod cake_filling_price;
+pub mod cookie;
 pub mod entity_linked;
 pub mod filling;
 pub mod fruit;
 pub mod indexes;
+pub mod ingredient;
+pub mod invoice;
+pub mod invoice_line;
 pub mod lunch_set;
+#[cfg(feature = "with-chrono")]
+pub mod recipe;
 pub mod rust_keyword;
 pub mod sea_orm_active_enums;
 pub mod vendor;
@@ -17,8 +23,14 @@ pub use cake::Entity as Cake;
 pub use cake_expanded::Entity as CakeExpanded;
 pub use cake_filling::Entity as CakeFilling;
 pub use cake_filling_price::Entity as CakeFillingPrice;
+pub use cookie::Entity as Cookie;
 pub use filling::Entity as Filling;
 pub use fruit::Entity as Fruit;
+pub use ingredient::Entity as Ingredient;
+pub use invoice::Entity as Invoice;
+pub use invoice_line::Entity as InvoiceLine;
 pub use lunch_set::Entity as LunchSet;
+#[cfg(feature = "with-chrono")]
+pub use recipe::Entity as Recipe;
 pub use rust_keyword::Entity as RustKeyword;
 pub use vendor::Entity as Vendor;
@@ -1,36 +1,46 @@
 pub mod active_enum;
 pub mod active_enum_child;
 pub mod applog;
+pub mod article;
 pub mod byte_primary_key;
 pub mod collection;
+pub mod comment;
 pub mod custom_active_model;
 pub mod event_trigger;
 pub mod insert_default;
 pub mod json_struct;
 pub mod json_vec;
 pub mod metadata;
+pub mod note;
 pub mod pi;
+pub mod project;
 pub mod repository;
 pub mod satellite;
 pub mod schema;
 pub mod sea_orm_active_enums;
 pub mod self_join;
+pub mod tag;
 pub mod transaction_log;
 
 pub use active_enum::Entity as ActiveEnum;
 pub use active_enum_child::Entity as ActiveEnumChild;
 pub use applog::Entity as Applog;
+pub use article::Entity as Article;
 pub use byte_primary_key::Entity as BytePrimaryKey;
 pub use collection::Entity as Collection;
+pub use comment::Entity as Comment;
 pub use event_trigger::Entity as EventTrigger;
 pub use insert_default::Entity as InsertDefault;
 pub use json_struct::Entity as JsonStruct;
 pub use json_vec::Entity as JsonVec;
 pub use metadata::Entity as Metadata;
+pub use note::Entity as Note;
 pub use pi::Entity as Pi;
+pub use project::Entity as Project;
 pub use repository::Entity as Repository;
 pub use satellite::Entity as Satellite;
 pub use schema::*;
 pub use sea_orm_active_enums::*;
 pub use self_join::Entity as SelfJoin;
+pub use tag::Entity as Tag;
 pub use transaction_log::Entity as TransactionLog;
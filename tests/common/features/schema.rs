@@ -42,6 +42,11 @@ pub async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     create_active_enum_child_table(db).await?;
     create_insert_default_table(db).await?;
     create_pi_table(db).await?;
+    create_tag_table(db).await?;
+    create_note_table(db).await?;
+    create_article_table(db).await?;
+    create_comment_table(db).await?;
+    create_project_table(db).await?;
 
     if DbBackend::Postgres == db_backend {
         create_collection_table(db).await?;
@@ -413,6 +418,107 @@ pub async fn create_pi_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     create_table(db, &stmt, Pi).await
 }
 
+pub async fn create_tag_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(tag::Entity)
+        .col(
+            ColumnDef::new(tag::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(tag::Column::Name)
+                .string()
+                .not_null()
+                .unique_key(),
+        )
+        .col(ColumnDef::new(tag::Column::Usage).integer().not_null())
+        .to_owned();
+
+    create_table(db, &stmt, Tag).await
+}
+
+pub async fn create_note_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(note::Entity)
+        .col(
+            ColumnDef::new(note::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(note::Column::Title).string().not_null())
+        .col(ColumnDef::new(note::Column::UpdatedAt).timestamp_with_time_zone())
+        .col(ColumnDef::new(note::Column::DeletedAt).timestamp_with_time_zone())
+        .to_owned();
+
+    create_table(db, &stmt, Note).await
+}
+
+pub async fn create_article_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(article::Entity)
+        .col(
+            ColumnDef::new(article::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(article::Column::Title).string().not_null())
+        .col(
+            ColumnDef::new(article::Column::Version)
+                .integer()
+                .not_null()
+                .default(0),
+        )
+        .to_owned();
+
+    create_table(db, &stmt, Article).await
+}
+
+pub async fn create_comment_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(comment::Entity)
+        .col(
+            ColumnDef::new(comment::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(comment::Column::Body).string().not_null())
+        .col(
+            ColumnDef::new(comment::Column::CreatedAt)
+                .timestamp_with_time_zone()
+                .not_null(),
+        )
+        .col(ColumnDef::new(comment::Column::UpdatedAt).timestamp_with_time_zone())
+        .to_owned();
+
+    create_table(db, &stmt, Comment).await
+}
+
+pub async fn create_project_table(db: &DbConn) -> Result<ExecResult, DbErr> {
+    let stmt = sea_query::Table::create()
+        .table(project::Entity)
+        .col(
+            ColumnDef::new(project::Column::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(project::Column::Tenant).string().not_null())
+        .col(ColumnDef::new(project::Column::Name).string().not_null())
+        .to_owned();
+
+    create_table(db, &stmt, Project).await
+}
+
 pub async fn create_event_trigger_table(db: &DbConn) -> Result<ExecResult, DbErr> {
     let stmt = sea_query::Table::create()
         .table(event_trigger::Entity)
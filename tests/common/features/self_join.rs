@@ -27,6 +27,18 @@ impl Linked for SelfReferencingLink {
     }
 }
 
+pub struct SelfReferencingReverseLink;
+
+impl Linked for SelfReferencingReverseLink {
+    type FromEntity = Entity;
+
+    type ToEntity = Entity;
+
+    fn link(&self) -> Vec<RelationDef> {
+        vec![Relation::SelfReferencing.def().rev()]
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 #[cfg(test)]
@@ -74,4 +86,33 @@ mod tests {
             .join(" ")
         );
     }
+
+    #[test]
+    fn find_linked_recursive_001() {
+        let self_join_model = Model {
+            uuid: Uuid::default(),
+            uuid_ref: None,
+            time: None,
+        };
+
+        assert_eq!(
+            self_join_model
+                .find_linked_recursive(SelfReferencingLink)
+                .build(DbBackend::Postgres)
+                .to_string(),
+            [
+                r#"WITH RECURSIVE "linked_recursive" ("uuid") AS"#,
+                r#"(SELECT "self_join"."uuid" FROM "self_join""#,
+                r#"INNER JOIN "self_join" AS "r0" ON "r0"."uuid_ref" = "self_join"."uuid""#,
+                r#"INNER JOIN (SELECT "self_join"."uuid" FROM "self_join" WHERE "self_join"."uuid" = '00000000-0000-0000-0000-000000000000') AS "linked_seed""#,
+                r#"ON "linked_seed"."uuid" = "r0"."uuid""#,
+                r#"UNION SELECT "self_join"."uuid" FROM "self_join""#,
+                r#"INNER JOIN "self_join" AS "r0" ON "r0"."uuid_ref" = "self_join"."uuid""#,
+                r#"INNER JOIN "linked_recursive" ON "linked_recursive"."uuid" = "r0"."uuid")"#,
+                r#"SELECT "self_join"."uuid", "self_join"."uuid_ref", "self_join"."time" FROM "self_join""#,
+                r#"WHERE "self_join"."uuid" IN (SELECT "uuid" FROM "linked_recursive")"#,
+            ]
+            .join(" ")
+        );
+    }
 }
@@ -30,19 +30,28 @@ pub async fn test_cake_error_sqlx(db: &DbConn) {
         .await
         .expect_err("inserting should fail due to duplicate primary key");
 
-    #[cfg(any(feature = "sqlx-mysql", feature = "sqlx-sqlite"))]
+    #[cfg(feature = "sqlx-mysql")]
     match error {
         DbErr::Exec(RuntimeErr::SqlxError(error)) => match error {
             Error::Database(e) => {
-                #[cfg(feature = "sqlx-mysql")]
                 assert_eq!(e.code().unwrap(), "23000");
-                #[cfg(feature = "sqlx-sqlite")]
-                assert_eq!(e.code().unwrap(), "1555");
             }
             _ => panic!("Unexpected sqlx-error kind"),
         },
         _ => panic!("Unexpected Error kind"),
     }
+    #[cfg(feature = "sqlx-sqlite")]
+    match error {
+        DbErr::Exec(RuntimeErr::SqlxError(error)) | DbErr::Query(RuntimeErr::SqlxError(error)) => {
+            match error {
+                Error::Database(e) => {
+                    assert_eq!(e.code().unwrap(), "1555");
+                }
+                _ => panic!("Unexpected sqlx-error kind"),
+            }
+        }
+        _ => panic!("Unexpected Error kind"),
+    }
     #[cfg(feature = "sqlx-postgres")]
     match error {
         DbErr::Query(RuntimeErr::SqlxError(error)) => match error {
@@ -0,0 +1,107 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_many_returning_tests").await;
+    create_tables(&ctx.db).await?;
+    insert_many_auto_increment(&ctx.db).await?;
+    insert_many_primary_key(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_many_auto_increment(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let tags = |names: &[&str]| {
+        names
+            .iter()
+            .map(|name| tag::ActiveModel {
+                name: Set(name.to_string()),
+                usage: Set(name.len() as i32),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let inserted = Tag::insert_many(tags(&["rust", "orm"]))
+        .exec_with_returning_many(db)
+        .await?;
+    assert_eq!(
+        inserted,
+        vec![
+            tag::Model {
+                id: 1,
+                name: "rust".to_owned(),
+                usage: 4,
+            },
+            tag::Model {
+                id: 2,
+                name: "orm".to_owned(),
+                usage: 3,
+            },
+        ]
+    );
+
+    let inserted = Tag::insert_many(tags(&["async", "sql", "sea"]))
+        .exec_with_returning_many(db)
+        .await?;
+    assert_eq!(
+        inserted
+            .iter()
+            .map(|tag| (tag.id, tag.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(3, "async"), (4, "sql"), (5, "sea")]
+    );
+
+    let inserted = Tag::insert_many(tags(&[]))
+        .exec_with_returning_many(db)
+        .await?;
+    assert_eq!(inserted, vec![]);
+    assert_eq!(Tag::find().all(db).await?.len(), 5);
+
+    Ok(())
+}
+
+pub async fn insert_many_primary_key(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let repositories = vec![
+        repository::Model {
+            id: "unique-id-003".to_owned(),
+            owner: "SeaQL".to_owned(),
+            name: "sea-query".to_owned(),
+            description: None,
+        },
+        repository::Model {
+            id: "unique-id-001".to_owned(),
+            owner: "SeaQL".to_owned(),
+            name: "sea-orm".to_owned(),
+            description: Some("An async & dynamic ORM".to_owned()),
+        },
+        repository::Model {
+            id: "unique-id-002".to_owned(),
+            owner: "SeaQL".to_owned(),
+            name: "sea-schema".to_owned(),
+            description: None,
+        },
+    ];
+
+    let inserted = Repository::insert_many(
+        repositories
+            .iter()
+            .cloned()
+            .map(|repository| repository.into_active_model()),
+    )
+    .exec_with_returning_many(db)
+    .await?;
+    assert_eq!(inserted, repositories);
+
+    Ok(())
+}
@@ -230,3 +230,433 @@ async fn loader_load_many() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn loader_load_many_to_many() -> Result<(), DbErr> {
+    use rust_decimal_macros::dec;
+
+    let ctx = TestContext::new("loader_test_load_many_to_many").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let baker_1 = baker::ActiveModel {
+        name: Set("Baker 1".to_owned()),
+        contact_details: Set(serde_json::json!({})),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert baker");
+
+    let baker_2 = baker::ActiveModel {
+        name: Set("Baker 2".to_owned()),
+        contact_details: Set(serde_json::json!({})),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert baker");
+
+    let cake_1 = cake::ActiveModel {
+        name: Set("Cheesecake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cake");
+
+    let cake_2 = cake::ActiveModel {
+        name: Set("Chocolate".to_owned()),
+        price: Set(dec!(2.5)),
+        gluten_free: Set(true),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cake");
+
+    let cake_3 = cake::ActiveModel {
+        name: Set("Plain".to_owned()),
+        price: Set(dec!(1.0)),
+        gluten_free: Set(true),
+        bakery_id: Set(Some(bakery.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cake");
+
+    for (cake_id, baker_id) in [
+        (cake_1.id, baker_1.id),
+        (cake_1.id, baker_2.id),
+        (cake_2.id, baker_2.id),
+    ] {
+        cakes_bakers::ActiveModel {
+            cake_id: Set(cake_id),
+            baker_id: Set(baker_id),
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert cakes_bakers");
+    }
+
+    let cakes = vec![cake_1, cake_2, cake_3];
+
+    let bakers = cakes
+        .load_many_to_many(baker::Entity::find(), cakes_bakers::Entity, &ctx.db)
+        .await
+        .expect("Should load bakers");
+
+    assert_eq!(
+        bakers,
+        vec![
+            vec![baker_1.clone(), baker_2.clone()],
+            vec![baker_2.clone()],
+            vec![]
+        ]
+    );
+
+    let bakers = cakes
+        .load_many_to_many(
+            baker::Entity::find().filter(baker::Column::Name.eq("Baker 1")),
+            cakes_bakers::Entity,
+            &ctx.db,
+        )
+        .await
+        .expect("Should load bakers");
+
+    assert_eq!(bakers, vec![vec![baker_1], vec![], vec![]]);
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn loader_load_nested() -> Result<(), DbErr> {
+    use rust_decimal_macros::dec;
+
+    let ctx = TestContext::new("loader_test_load_nested").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery_1 = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let bakery_2 = bakery::ActiveModel {
+        name: Set("Offshore Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let baker_1 = baker::ActiveModel {
+        name: Set("Baker 1".to_owned()),
+        contact_details: Set(serde_json::json!({})),
+        bakery_id: Set(Some(bakery_1.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert baker");
+
+    let baker_2 = baker::ActiveModel {
+        name: Set("Baker 2".to_owned()),
+        contact_details: Set(serde_json::json!({})),
+        bakery_id: Set(Some(bakery_1.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert baker");
+
+    let cake_1 = cake::ActiveModel {
+        name: Set("Cheesecake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        bakery_id: Set(Some(bakery_1.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cake");
+
+    let cake_2 = cake::ActiveModel {
+        name: Set("Chocolate".to_owned()),
+        price: Set(dec!(2.5)),
+        gluten_free: Set(true),
+        bakery_id: Set(Some(bakery_1.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cake");
+
+    for (cake_id, baker_id) in [(cake_1.id, baker_1.id), (cake_2.id, baker_2.id)] {
+        cakes_bakers::ActiveModel {
+            cake_id: Set(cake_id),
+            baker_id: Set(baker_id),
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert cakes_bakers");
+    }
+
+    let bakeries = bakery::Entity::find()
+        .all(&ctx.db)
+        .await
+        .expect("Should load bakeries");
+
+    let bakers = bakeries
+        .load_many(baker::Entity::find(), &ctx.db)
+        .await
+        .expect("Should load bakers");
+
+    assert_eq!(bakers, vec![vec![baker_1.clone(), baker_2.clone()], vec![]]);
+
+    let cakes = bakers
+        .load_many_to_many(cake::Entity::find(), cakes_bakers::Entity, &ctx.db)
+        .await
+        .expect("Should load cakes");
+
+    assert_eq!(
+        cakes,
+        vec![vec![vec![cake_1.clone()], vec![cake_2.clone()]], vec![]]
+    );
+
+    let cake_bakeries = cakes
+        .load_one(bakery::Entity::find(), &ctx.db)
+        .await
+        .expect("Should load bakeries");
+
+    assert_eq!(
+        cake_bakeries,
+        vec![
+            vec![vec![Some(bakery_1.clone())], vec![Some(bakery_1.clone())]],
+            vec![]
+        ]
+    );
+
+    let cake_bakers = cakes
+        .load_many_to_many(baker::Entity::find(), cakes_bakers::Entity, &ctx.db)
+        .await
+        .expect("Should load bakers");
+
+    assert_eq!(
+        cake_bakers,
+        vec![
+            vec![vec![vec![baker_1.clone()]], vec![vec![baker_2.clone()]]],
+            vec![]
+        ]
+    );
+
+    assert_eq!(bakeries, vec![bakery_1, bakery_2]);
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn loader_load_many_with_limit() -> Result<(), DbErr> {
+    let ctx = TestContext::new("loader_test_load_many_with_limit").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery_1 = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let bakery_2 = bakery::ActiveModel {
+        name: Set("Offshore Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let mut bakers = Vec::new();
+    for (name, bakery_id) in [
+        ("Baker 1", bakery_1.id),
+        ("Baker 2", bakery_1.id),
+        ("Baker 3", bakery_1.id),
+        ("John", bakery_1.id),
+        ("Baker 4", bakery_2.id),
+    ] {
+        let baker = baker::ActiveModel {
+            name: Set(name.to_owned()),
+            contact_details: Set(serde_json::json!({})),
+            bakery_id: Set(Some(bakery_id)),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert baker");
+        bakers.push(baker);
+    }
+
+    let bakeries = vec![bakery_1, bakery_2];
+
+    let latest_bakers = bakeries
+        .load_many_with_limit(
+            baker::Entity::find().filter(baker::Column::Name.like("Baker%")),
+            vec![(baker::Column::Id, sea_orm::Order::Desc)],
+            2,
+            &ctx.db,
+        )
+        .await
+        .expect("Should load bakers");
+
+    assert_eq!(
+        latest_bakers,
+        vec![
+            vec![bakers[2].clone(), bakers[1].clone()],
+            vec![bakers[4].clone()]
+        ]
+    );
+
+    Ok(())
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn loader_load_count() -> Result<(), DbErr> {
+    use rust_decimal_macros::dec;
+    use sea_orm::sea_query::Expr;
+
+    let ctx = TestContext::new("loader_test_load_count").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery_1 = bakery::ActiveModel {
+        name: Set("SeaSide Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let bakery_2 = bakery::ActiveModel {
+        name: Set("Offshore Bakery".to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let mut bakers = Vec::new();
+    for name in ["Baker 1", "Baker 2", "John"] {
+        let baker = baker::ActiveModel {
+            name: Set(name.to_owned()),
+            contact_details: Set(serde_json::json!({})),
+            bakery_id: Set(Some(bakery_1.id)),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert baker");
+        bakers.push(baker);
+    }
+
+    let cake = cake::ActiveModel {
+        name: Set("Cheesecake".to_owned()),
+        price: Set(dec!(10.25)),
+        gluten_free: Set(false),
+        bakery_id: Set(Some(bakery_1.id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cake");
+
+    for baker in bakers.iter().take(2) {
+        cakes_bakers::ActiveModel {
+            cake_id: Set(cake.id),
+            baker_id: Set(baker.id),
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert cakes_bakers");
+    }
+
+    let bakeries = vec![bakery_1, bakery_2];
+
+    let counts = bakeries
+        .load_count(baker::Entity::find(), &ctx.db)
+        .await
+        .expect("Should count bakers");
+
+    assert_eq!(counts, vec![3, 0]);
+
+    let counts = bakeries
+        .load_count(
+            baker::Entity::find().filter(baker::Column::Name.like("Baker%")),
+            &ctx.db,
+        )
+        .await
+        .expect("Should count bakers");
+
+    assert_eq!(counts, vec![2, 0]);
+
+    let names: Vec<Option<String>> = bakeries
+        .load_aggregate(
+            baker::Entity::find(),
+            Expr::tbl(baker::Entity, baker::Column::Name).max(),
+            &ctx.db,
+        )
+        .await
+        .expect("Should aggregate bakers");
+
+    assert_eq!(names, vec![Some("John".to_owned()), None]);
+
+    let counts = vec![cake]
+        .load_count(baker::Entity::find(), &ctx.db)
+        .await
+        .expect("Should count bakers");
+
+    assert_eq!(counts, vec![2]);
+
+    Ok(())
+}
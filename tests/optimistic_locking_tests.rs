@@ -0,0 +1,112 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("optimistic_locking_tests").await;
+    create_tables(&ctx.db).await?;
+    optimistic_locking(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn optimistic_locking(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let draft = article::ActiveModel {
+        title: Set("Draft".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(draft.version, 0);
+
+    let mut first = draft.clone().into_active_model();
+    first.title = Set("First".to_owned());
+    let first = first.update(db).await?;
+    assert_eq!(
+        first,
+        article::Model {
+            id: 1,
+            title: "First".to_owned(),
+            version: 1,
+        }
+    );
+
+    let mut second = draft.into_active_model();
+    second.title = Set("Second".to_owned());
+    assert_eq!(
+        second.update(db).await,
+        Err(DbErr::StaleRecord(
+            "The record has been modified since it was read".to_owned()
+        ))
+    );
+
+    let mut second = first.into_active_model();
+    second.title = Set("Second".to_owned());
+    let second = second.save(db).await?;
+    assert_eq!(second.version, Unchanged(2));
+
+    assert_eq!(
+        Article::find_by_id(1).one(db).await?,
+        Some(article::Model {
+            id: 1,
+            title: "Second".to_owned(),
+            version: 2,
+        })
+    );
+
+    let third = article::ActiveModel {
+        title: Set("Third".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert_eq!(
+        Article::update_batch(vec![
+            article::ActiveModel {
+                id: Unchanged(1),
+                title: Set("Stale".to_owned()),
+                version: Unchanged(1),
+            },
+            article::ActiveModel {
+                id: Unchanged(third.id),
+                title: Set("Fourth".to_owned()),
+                version: Unchanged(third.version),
+            },
+        ])
+        .exec(db)
+        .await,
+        Err(DbErr::StaleRecord(
+            "1 of the 2 versioned records have been modified since they were read".to_owned()
+        ))
+    );
+
+    assert_eq!(
+        Article::find()
+            .order_by_asc(article::Column::Id)
+            .all(db)
+            .await?,
+        vec![
+            article::Model {
+                id: 1,
+                title: "Second".to_owned(),
+                version: 2,
+            },
+            article::Model {
+                id: 2,
+                title: "Fourth".to_owned(),
+                version: 1,
+            },
+        ]
+    );
+
+    Ok(())
+}
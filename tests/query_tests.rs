@@ -222,3 +222,445 @@ pub async fn find_all_filter_with_results() {
 
     ctx.delete().await;
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_aggregates() {
+    use sea_orm::{QueryOrder, QuerySelect};
+
+    let ctx = TestContext::new("find_aggregates").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    let empty = Bakery::find().exists(&ctx.db).await.unwrap();
+    assert!(!empty);
+
+    let max: Option<f64> = Bakery::find()
+        .max(bakery::Column::ProfitMargin, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(max, None);
+
+    for (name, profit_margin) in [
+        ("SeaSide Bakery", 10.0),
+        ("Top Bakery", 15.0),
+        ("Bottom Bakery", 5.0),
+    ] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(profit_margin),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let exists = Bakery::find()
+        .filter(bakery::Column::Name.contains("Top"))
+        .exists(&ctx.db)
+        .await
+        .unwrap();
+    assert!(exists);
+
+    let sum: Option<f64> = Bakery::find()
+        .sum(bakery::Column::ProfitMargin, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(sum, Some(30.0));
+
+    let avg: Option<f64> = Bakery::find()
+        .filter(bakery::Column::ProfitMargin.gt(6.0))
+        .avg(bakery::Column::ProfitMargin, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(avg, Some(12.5));
+
+    let min: Option<f64> = Bakery::find()
+        .min(bakery::Column::ProfitMargin, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(min, Some(5.0));
+
+    let max: Option<String> = Bakery::find()
+        .order_by_asc(bakery::Column::ProfitMargin)
+        .limit(2)
+        .max(bakery::Column::Name, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(max, Some("SeaSide Bakery".to_owned()));
+
+    let top_bakery = Bakery::find()
+        .filter(bakery::Column::Name.eq("Top Bakery"))
+        .one(&ctx.db)
+        .await
+        .unwrap()
+        .unwrap();
+
+    for name in ["Baker 1", "Baker 2"] {
+        baker::ActiveModel {
+            name: Set(name.to_owned()),
+            contact_details: Set(serde_json::json!({})),
+            bakery_id: Set(Some(top_bakery.id)),
+            ..Default::default()
+        }
+        .save(&ctx.db)
+        .await
+        .expect("could not insert baker");
+    }
+
+    let count = Bakery::find()
+        .find_with_related(Baker)
+        .order_by_desc(baker::Column::Name)
+        .count(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(count, 3);
+
+    let max: Option<String> = Bakery::find()
+        .find_also_related(Baker)
+        .max(baker::Column::Name, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(max, Some("Baker 2".to_owned()));
+
+    bakery::ActiveModel {
+        name: Set("Side Bakery".to_owned()),
+        profit_margin: Set(10.0),
+        ..Default::default()
+    }
+    .save(&ctx.db)
+    .await
+    .expect("could not insert bakery");
+
+    let sum: Option<f64> = Bakery::find()
+        .select_only()
+        .column(bakery::Column::ProfitMargin)
+        .group_by(bakery::Column::ProfitMargin)
+        .sum(bakery::Column::ProfitMargin, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(sum, Some(30.0));
+
+    let sum: Option<f64> = Bakery::find()
+        .sum(bakery::Column::ProfitMargin, &ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(sum, Some(40.0));
+
+    let exists = Bakery::find()
+        .find_also_related(Baker)
+        .filter(baker::Column::Name.eq("Baker 2"))
+        .exists(&ctx.db)
+        .await
+        .unwrap();
+    assert!(exists);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_filter_has() {
+    use rust_decimal_macros::dec;
+
+    let ctx = TestContext::new("find_filter_has").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    let mut bakeries = Vec::new();
+    for name in ["SeaSide Bakery", "Top Bakery"] {
+        let bakery = bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(10.0),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+        bakeries.push(bakery);
+    }
+
+    let baker = baker::ActiveModel {
+        name: Set("Baker Bob".to_owned()),
+        contact_details: Set(serde_json::json!({})),
+        bakery_id: Set(Some(bakeries[0].id)),
+        ..Default::default()
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert baker");
+
+    let mut cakes = Vec::new();
+    for name in ["Cheesecake", "Chocolate"] {
+        let cake = cake::ActiveModel {
+            name: Set(name.to_owned()),
+            price: Set(dec!(10.25)),
+            gluten_free: Set(false),
+            bakery_id: Set(Some(bakeries[0].id)),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert cake");
+        cakes.push(cake);
+    }
+
+    cakes_bakers::ActiveModel {
+        cake_id: Set(cakes[0].id),
+        baker_id: Set(baker.id),
+    }
+    .insert(&ctx.db)
+    .await
+    .expect("could not insert cakes_bakers");
+
+    let with_bakers = Bakery::find()
+        .filter_has::<baker::Entity>(|q| q.filter(baker::Column::Name.contains("Bob")))
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(with_bakers, vec![bakeries[0].clone()]);
+
+    let without_bakers = Bakery::find()
+        .filter_has_not::<baker::Entity>(|q| q)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(without_bakers, vec![bakeries[1].clone()]);
+
+    let baked_by_bob = Cake::find()
+        .filter_has::<baker::Entity>(|q| q.filter(baker::Column::Id.eq(baker.id)))
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(baked_by_bob, vec![cakes[0].clone()]);
+
+    let not_baked = Cake::find()
+        .filter_has_not::<baker::Entity>(|q| q)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(not_baked, vec![cakes[1].clone()]);
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_union() {
+    use sea_orm::{CursorTrait, PaginatorTrait, QueryOrder, QuerySelect};
+
+    let ctx = TestContext::new("find_union").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    let mut bakeries = Vec::new();
+    for (name, profit_margin) in [
+        ("SeaSide Bakery", 10.0),
+        ("Top Bakery", 15.0),
+        ("Bottom Bakery", 5.0),
+        ("Side Bakery", 20.0),
+    ] {
+        let bakery = bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(profit_margin),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+        bakeries.push(bakery);
+    }
+
+    let side = || Bakery::find().filter(bakery::Column::Name.contains("Side"));
+    let profitable = || Bakery::find().filter(bakery::Column::ProfitMargin.gte(10.0));
+
+    let union = side()
+        .union(profitable())
+        .order_by_asc(bakery::Column::Id)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(
+        union,
+        vec![
+            bakeries[0].clone(),
+            bakeries[1].clone(),
+            bakeries[3].clone()
+        ]
+    );
+
+    let count = side().union_all(profitable()).count(&ctx.db).await.unwrap();
+    assert_eq!(count, 5);
+
+    let top = side()
+        .union(
+            profitable()
+                .order_by_desc(bakery::Column::ProfitMargin)
+                .limit(1),
+        )
+        .order_by_asc(bakery::Column::Id)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(top, vec![bakeries[0].clone(), bakeries[3].clone()]);
+
+    let paginator = side()
+        .union(profitable())
+        .order_by_desc(bakery::Column::Id)
+        .paginate(&ctx.db, 2);
+    assert_eq!(paginator.num_items().await.unwrap(), 3);
+    assert_eq!(
+        paginator.fetch_page(1).await.unwrap(),
+        vec![bakeries[0].clone()]
+    );
+
+    let cursor = side()
+        .union(profitable())
+        .cursor_by(bakery::Column::Id)
+        .after(bakeries[0].id)
+        .first(1)
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(cursor, vec![bakeries[1].clone()]);
+
+    #[cfg(not(feature = "sqlx-mysql"))]
+    {
+        let intersect = side()
+            .intersect(profitable())
+            .order_by_asc(bakery::Column::Id)
+            .all(&ctx.db)
+            .await
+            .unwrap();
+        assert_eq!(intersect, vec![bakeries[0].clone(), bakeries[3].clone()]);
+
+        let except = side().except(profitable()).all(&ctx.db).await.unwrap();
+        assert_eq!(except, vec![]);
+    }
+
+    ctx.delete().await;
+}
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn find_window_functions() {
+    use sea_orm::{
+        DeriveColumn, EnumIter, FromQueryResult, IntoSimpleExpr, Order, OrderedStatement,
+        QueryOrder, QuerySelect, WindowStatement,
+    };
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
+    enum QueryAs {
+        BakeryName,
+        RowNumber,
+    }
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct RankedBakery {
+        name: String,
+        rank: i64,
+        previous_name: Option<String>,
+        running_total: f64,
+    }
+
+    let ctx = TestContext::new("find_window_functions").await;
+    create_tables(&ctx.db).await.unwrap();
+
+    for (name, profit_margin) in [
+        ("SeaSide Bakery", 10.0),
+        ("Top Bakery", 15.0),
+        ("Bottom Bakery", 5.0),
+    ] {
+        bakery::ActiveModel {
+            name: Set(name.to_owned()),
+            profit_margin: Set(profit_margin),
+            ..Default::default()
+        }
+        .insert(&ctx.db)
+        .await
+        .expect("could not insert bakery");
+    }
+
+    let by_profit = WindowStatement::new()
+        .order_by_expr(bakery::Column::ProfitMargin.into_simple_expr(), Order::Desc)
+        .to_owned();
+
+    let ranked: Vec<RankedBakery> = Bakery::find()
+        .select_only()
+        .column(bakery::Column::Name)
+        .rank(by_profit.clone(), "rank")
+        .lag(bakery::Column::Name, 1, by_profit.clone(), "previous_name")
+        .column_as_window(
+            bakery::Column::ProfitMargin.sum(),
+            by_profit,
+            "running_total",
+        )
+        .order_by_desc(bakery::Column::ProfitMargin)
+        .into_model()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        ranked,
+        vec![
+            RankedBakery {
+                name: "Top Bakery".to_owned(),
+                rank: 1,
+                previous_name: None,
+                running_total: 15.0,
+            },
+            RankedBakery {
+                name: "SeaSide Bakery".to_owned(),
+                rank: 2,
+                previous_name: Some("Top Bakery".to_owned()),
+                running_total: 25.0,
+            },
+            RankedBakery {
+                name: "Bottom Bakery".to_owned(),
+                rank: 3,
+                previous_name: Some("SeaSide Bakery".to_owned()),
+                running_total: 30.0,
+            },
+        ]
+    );
+
+    let row_numbers: Vec<(String, i64)> = Bakery::find()
+        .select_only()
+        .column_as(bakery::Column::Name, QueryAs::BakeryName)
+        .row_number(
+            WindowStatement::new()
+                .order_by_expr(bakery::Column::Name.into_simple_expr(), Order::Asc)
+                .to_owned(),
+            QueryAs::RowNumber,
+        )
+        .order_by_asc(bakery::Column::Name)
+        .into_values::<_, QueryAs>()
+        .all(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(
+        row_numbers,
+        vec![
+            ("Bottom Bakery".to_owned(), 1),
+            ("SeaSide Bakery".to_owned(), 2),
+            ("Top Bakery".to_owned(), 3),
+        ]
+    );
+
+    ctx.delete().await;
+}
@@ -2,7 +2,7 @@ pub mod common;
 
 pub use common::{bakery_chain::*, setup::*, TestContext};
 pub use sea_orm::{entity::prelude::*, *};
-pub use sea_query::Query;
+pub use sea_query::{Expr, Query};
 
 #[sea_orm_macros::test]
 #[cfg(any(
@@ -37,6 +37,12 @@ async fn main() -> Result<(), DbErr> {
 
     create_tables(db).await?;
 
+    match builder {
+        DbBackend::Postgres => assert!(db.support_returning()),
+        DbBackend::Sqlite => assert!(db.support_returning()),
+        DbBackend::MySql => assert!(!db.support_update_returning()),
+    }
+
     if db.support_returning() {
         insert.returning(returning.clone());
         let insert_res = db
@@ -55,6 +61,7 @@ async fn main() -> Result<(), DbErr> {
         let _id: i32 = update_res.try_get("", "id")?;
         let _name: String = update_res.try_get("", "name")?;
         let _profit_margin: f64 = update_res.try_get("", "profit_margin")?;
+        assert!(update_res.try_get::<f64>("", "name").is_err());
     } else {
         let insert_res = db.execute(builder.build(&insert)).await?;
         assert!(insert_res.rows_affected() > 0);
@@ -67,3 +74,99 @@ async fn main() -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn update_many_and_delete_many() -> Result<(), DbErr> {
+    use bakery::*;
+
+    let ctx = TestContext::new("returning_many_tests").await;
+    let db = &ctx.db;
+
+    create_tables(db).await?;
+
+    Entity::insert_many(
+        ["Bakery Shop", "Bakery Cafe", "Pastry Shop"]
+            .into_iter()
+            .map(|name| ActiveModel {
+                name: Set(name.to_owned()),
+                profit_margin: Set(0.5),
+                ..Default::default()
+            }),
+    )
+    .exec(db)
+    .await?;
+
+    let updated = Entity::update_many()
+        .col_expr(Column::ProfitMargin, Expr::value(0.8))
+        .filter(Column::Name.starts_with("Bakery"))
+        .exec_with_returning(db)
+        .await;
+    let deleted = Entity::delete_many()
+        .filter(Column::Name.ends_with("Shop"))
+        .exec_with_returning(db)
+        .await;
+
+    let err = || DbErr::BackendNotSupported {
+        db: db.get_database_backend().as_str(),
+        ctx: "RETURNING",
+    };
+
+    if db.support_update_returning() {
+        let mut updated = updated?;
+        updated.sort_by_key(|bakery| bakery.id);
+        assert_eq!(
+            updated,
+            vec![
+                Model {
+                    id: 1,
+                    name: "Bakery Shop".to_owned(),
+                    profit_margin: 0.8,
+                },
+                Model {
+                    id: 2,
+                    name: "Bakery Cafe".to_owned(),
+                    profit_margin: 0.8,
+                },
+            ]
+        );
+    } else {
+        assert_eq!(updated, Err(err()));
+    }
+
+    if db.support_returning() {
+        let mut deleted = deleted?;
+        deleted.sort_by_key(|bakery| bakery.id);
+        assert_eq!(
+            deleted,
+            vec![
+                Model {
+                    id: 1,
+                    name: "Bakery Shop".to_owned(),
+                    profit_margin: if db.support_update_returning() {
+                        0.8
+                    } else {
+                        0.5
+                    },
+                },
+                Model {
+                    id: 3,
+                    name: "Pastry Shop".to_owned(),
+                    profit_margin: 0.5,
+                },
+            ]
+        );
+        assert_eq!(Entity::find().count(db).await?, 1);
+    } else {
+        assert_eq!(deleted, Err(err()));
+        assert_eq!(Entity::find().count(db).await?, 3);
+    }
+
+    ctx.delete().await;
+
+    Ok(())
+}
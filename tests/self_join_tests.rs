@@ -87,3 +87,108 @@ pub async fn create_metadata(db: &DatabaseConnection) -> Result<(), DbErr> {
 
     Ok(())
 }
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn find_linked_recursive() -> Result<(), DbErr> {
+    use futures::TryStreamExt;
+
+    let ctx = TestContext::new("self_join_recursive_tests").await;
+    create_tables(&ctx.db).await?;
+
+    let mut models: Vec<self_join::Model> = Vec::new();
+    for hour in 1..=4 {
+        let model = self_join::Model {
+            uuid: Uuid::new_v4(),
+            uuid_ref: if hour < 4 {
+                models.last().map(|parent| parent.uuid)
+            } else {
+                None
+            },
+            time: Some(Time::from_hms_opt(hour, 00, 00).unwrap()),
+        };
+        model.clone().into_active_model().insert(&ctx.db).await?;
+        models.push(model);
+    }
+
+    assert_eq!(
+        models[2]
+            .find_linked_recursive(self_join::SelfReferencingLink)
+            .order_by_asc(self_join::Column::Time)
+            .all(&ctx.db)
+            .await?,
+        vec![models[0].clone(), models[1].clone()]
+    );
+
+    assert_eq!(
+        models[0]
+            .find_linked_recursive(self_join::SelfReferencingReverseLink)
+            .order_by_asc(self_join::Column::Time)
+            .all(&ctx.db)
+            .await?,
+        vec![models[1].clone(), models[2].clone()]
+    );
+
+    assert_eq!(
+        self_join::Entity::find()
+            .filter(self_join::Column::UuidRef.is_null())
+            .find_linked_recursive(self_join::SelfReferencingReverseLink)
+            .order_by_asc(self_join::Column::Time)
+            .all(&ctx.db)
+            .await?,
+        vec![models[1].clone(), models[2].clone()]
+    );
+
+    assert_eq!(
+        models[3]
+            .find_linked_recursive(self_join::SelfReferencingLink)
+            .one(&ctx.db)
+            .await?,
+        None
+    );
+
+    let paginator = models[0]
+        .find_linked_recursive(self_join::SelfReferencingReverseLink)
+        .order_by_asc(self_join::Column::Time)
+        .paginate(&ctx.db, 1);
+    assert_eq!(paginator.num_items().await?, 2);
+    assert_eq!(paginator.fetch_page(1).await?, vec![models[2].clone()]);
+
+    assert_eq!(
+        models[0]
+            .find_linked_recursive(self_join::SelfReferencingReverseLink)
+            .order_by_asc(self_join::Column::Time)
+            .stream(&ctx.db)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?,
+        vec![models[1].clone(), models[2].clone()]
+    );
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct LinkedTime {
+        time: Option<Time>,
+    }
+
+    assert_eq!(
+        models[0]
+            .find_linked_recursive(self_join::SelfReferencingReverseLink)
+            .select_only()
+            .column(self_join::Column::Time)
+            .order_by_desc(self_join::Column::Time)
+            .into_model::<LinkedTime>()
+            .one(&ctx.db)
+            .await?,
+        Some(LinkedTime {
+            time: models[2].time
+        })
+    );
+
+    ctx.delete().await;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("soft_delete_tests").await;
+    create_tables(&ctx.db).await?;
+    soft_delete(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn soft_delete(db: &DatabaseConnection) -> Result<(), DbErr> {
+    Note::insert_many(
+        ["todo", "shopping", "ideas", "journal"]
+            .into_iter()
+            .map(|title| note::ActiveModel {
+                title: Set(title.to_owned()),
+                ..Default::default()
+            }),
+    )
+    .exec(db)
+    .await?;
+
+    let titles =
+        |notes: Vec<note::Model>| notes.into_iter().map(|note| note.title).collect::<Vec<_>>();
+
+    let todo = Note::find_by_id(1).one(db).await?.unwrap();
+    assert_eq!(todo.deleted_at, None);
+
+    let res = todo.delete(db).await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(Note::find_by_id(1).one(db).await?, None);
+
+    let res = Note::delete_many()
+        .filter(note::Column::Title.is_in(["todo", "shopping"]))
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 1);
+
+    assert_eq!(
+        titles(Note::find().order_by_asc(note::Column::Id).all(db).await?),
+        vec!["ideas", "journal"]
+    );
+    assert_eq!(
+        titles(
+            Note::with_trashed()
+                .order_by_asc(note::Column::Id)
+                .all(db)
+                .await?
+        ),
+        vec!["todo", "shopping", "ideas", "journal"]
+    );
+
+    let trashed = Note::only_trashed()
+        .order_by_asc(note::Column::Id)
+        .all(db)
+        .await?;
+    assert!(trashed.iter().all(|note| note.deleted_at.is_some()));
+    assert!(trashed
+        .iter()
+        .all(|note| note.updated_at == note.deleted_at));
+    assert_eq!(titles(trashed), vec!["todo", "shopping"]);
+
+    let res = Note::force_delete_many()
+        .filter(note::Column::Title.is_in(["shopping", "ideas"]))
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 2);
+
+    let res = Note::delete_by_id(4).exec(db).await?.rows_affected;
+    assert_eq!(res, 1);
+    let res = Note::force_delete(note::ActiveModel {
+        id: Unchanged(4),
+        ..Default::default()
+    })
+    .exec(db)
+    .await?;
+    assert_eq!(res.rows_affected, 1);
+
+    assert_eq!(titles(Note::find().all(db).await?), Vec::<String>::new());
+    assert_eq!(titles(Note::with_trashed().all(db).await?), vec!["todo"]);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, sea_query::Expr, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("tenant_tests").await;
+    create_tables(&ctx.db).await?;
+    tenant_connection(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn tenant_connection(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let acme = db.with_tenant("acme");
+    let globex = db.with_tenant("globex");
+    let project = |name: &str| project::ActiveModel {
+        name: Set(name.to_owned()),
+        ..Default::default()
+    };
+
+    let rocket = project("Rocket").insert(&acme).await?;
+    assert_eq!(rocket.tenant, "acme");
+    Project::insert_many([project("Anvil"), project("Magnet")])
+        .exec(&acme)
+        .await?;
+    Project::insert(project("Hammock")).exec(&globex).await?;
+
+    let names = |projects: Vec<project::Model>| {
+        projects
+            .into_iter()
+            .map(|project| project.name)
+            .collect::<Vec<_>>()
+    };
+    let acme_projects = Project::find().order_by_asc(project::Column::Id);
+    assert_eq!(
+        names(acme_projects.clone().all(&acme).await?),
+        ["Rocket", "Anvil", "Magnet"]
+    );
+    assert_eq!(names(Project::find().all(&globex).await?), ["Hammock"]);
+    assert_eq!(Project::find().count(db).await?, 4);
+    assert_eq!(Project::find_by_id(rocket.id).one(&globex).await?, None);
+
+    let res = Project::update_many()
+        .col_expr(project::Column::Name, Expr::value("Renamed"))
+        .exec(&globex)
+        .await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(
+        project::ActiveModel {
+            name: Set("Stolen".to_owned()),
+            ..rocket.clone().into_active_model()
+        }
+        .update(&globex)
+        .await,
+        Err(DbErr::RecordNotFound(
+            "None of the database rows are affected".to_owned()
+        ))
+    );
+
+    let res = Project::delete_many().exec(&globex).await?;
+    assert_eq!(res.rows_affected, 1);
+    assert_eq!(
+        names(acme_projects.all(&acme).await?),
+        ["Rocket", "Anvil", "Magnet"]
+    );
+
+    Ok(())
+}
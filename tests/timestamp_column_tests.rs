@@ -0,0 +1,97 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, sea_query::Expr, DatabaseConnection};
+use std::time::Duration;
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("timestamp_column_tests").await;
+    create_tables(&ctx.db).await?;
+    created_at_and_updated_at(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn created_at_and_updated_at(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let before = chrono::Utc::now() - chrono::Duration::seconds(1);
+
+    let first = comment::ActiveModel {
+        body: Set("First!".to_owned()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+    assert!(first.created_at > before);
+    assert!(first.updated_at.is_some());
+
+    Comment::insert_many(
+        ["Second", "Third"]
+            .into_iter()
+            .map(|body| comment::ActiveModel {
+                body: Set(body.to_owned()),
+                ..Default::default()
+            }),
+    )
+    .exec(db)
+    .await?;
+
+    let comments = Comment::find()
+        .order_by_asc(comment::Column::Id)
+        .all(db)
+        .await?;
+    assert_eq!(comments.len(), 3);
+    assert!(comments
+        .iter()
+        .all(|comment| comment.created_at > before && comment.updated_at.is_some()));
+
+    std::thread::sleep(Duration::from_millis(10));
+
+    let mut edited = first.clone().into_active_model();
+    edited.body = Set("First, edited".to_owned());
+    let edited = edited.update(db).await?;
+    assert_eq!(edited.created_at, first.created_at);
+    assert!(edited.updated_at > first.updated_at);
+
+    std::thread::sleep(Duration::from_millis(10));
+
+    let res = Comment::update_many()
+        .col_expr(comment::Column::Body, Expr::value("Hidden"))
+        .filter(comment::Column::Id.gt(1))
+        .exec(db)
+        .await?;
+    assert_eq!(res.rows_affected, 2);
+
+    let hidden = Comment::find()
+        .filter(comment::Column::Id.gt(1))
+        .order_by_asc(comment::Column::Id)
+        .all(db)
+        .await?;
+    for (comment, before) in hidden.iter().zip(comments.iter().skip(1)) {
+        assert_eq!(comment.body, "Hidden");
+        assert_eq!(comment.created_at, before.created_at);
+        assert!(comment.updated_at > before.updated_at);
+    }
+
+    Comment::update_many()
+        .set(comment::ActiveModel {
+            updated_at: Set(None),
+            ..Default::default()
+        })
+        .filter(comment::Column::Id.eq(3))
+        .exec(db)
+        .await?;
+    assert_eq!(
+        Comment::find_by_id(3).one(db).await?.unwrap().updated_at,
+        None
+    );
+
+    Ok(())
+}
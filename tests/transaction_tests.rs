@@ -693,6 +693,49 @@ pub async fn transaction_nested() {
     ctx.delete().await;
 }
 
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+pub async fn transaction_savepoint() -> Result<(), DbErr> {
+    let ctx = TestContext::new("transaction_savepoint_test").await;
+    create_tables(&ctx.db).await?;
+
+    let bakery = |name: &str| bakery::ActiveModel {
+        name: Set(name.to_owned()),
+        profit_margin: Set(10.4),
+        ..Default::default()
+    };
+
+    let txn = ctx.db.begin().await?;
+    bakery("SeaSide Bakery").save(&txn).await?;
+
+    txn.savepoint("import").await?;
+    bakery("Top Bakery").save(&txn).await?;
+    assert_eq!(bakery::Entity::find().all(&txn).await?.len(), 2);
+    txn.rollback_to("import").await?;
+    assert_eq!(bakery::Entity::find().all(&txn).await?.len(), 1);
+
+    bakery("Rock n Roll Bakery").save(&txn).await?;
+    txn.release("import").await?;
+    txn.commit().await?;
+
+    let bakeries: Vec<String> = bakery::Entity::find()
+        .order_by_asc(bakery::Column::Id)
+        .all(&ctx.db)
+        .await?
+        .into_iter()
+        .map(|bakery| bakery.name)
+        .collect();
+    assert_eq!(bakeries, vec!["SeaSide Bakery", "Rock n Roll Bakery"]);
+
+    ctx.delete().await;
+
+    Ok(())
+}
+
 #[sea_orm_macros::test]
 #[cfg(any(
     feature = "sqlx-mysql",
@@ -0,0 +1,109 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, query::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("update_batch_tests").await;
+    create_tables(&ctx.db).await?;
+    update_batch(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn update_batch(db: &DatabaseConnection) -> Result<(), DbErr> {
+    Tag::insert_many(
+        ["rust", "orm", "sql", "async"]
+            .into_iter()
+            .map(|name| tag::ActiveModel {
+                name: Set(name.to_owned()),
+                usage: Set(0),
+                ..Default::default()
+            }),
+    )
+    .exec(db)
+    .await?;
+
+    let res = Tag::update_batch(vec![
+        tag::ActiveModel {
+            id: Unchanged(1),
+            usage: Set(10),
+            ..Default::default()
+        },
+        tag::ActiveModel {
+            id: Unchanged(2),
+            name: Set("sea-orm".to_owned()),
+            usage: Set(20),
+        },
+        tag::ActiveModel {
+            id: Unchanged(3),
+            usage: Set(30),
+            ..Default::default()
+        },
+        tag::ActiveModel {
+            id: Unchanged(4),
+            name: Unchanged("async".to_owned()),
+            usage: NotSet,
+        },
+    ])
+    .chunk_size(1)
+    .exec(db)
+    .await?;
+    assert_eq!(res.rows_affected, 3);
+
+    assert_eq!(
+        Tag::find().order_by_asc(tag::Column::Id).all(db).await?,
+        vec![
+            tag::Model {
+                id: 1,
+                name: "rust".to_owned(),
+                usage: 10,
+            },
+            tag::Model {
+                id: 2,
+                name: "sea-orm".to_owned(),
+                usage: 20,
+            },
+            tag::Model {
+                id: 3,
+                name: "sql".to_owned(),
+                usage: 30,
+            },
+            tag::Model {
+                id: 4,
+                name: "async".to_owned(),
+                usage: 0,
+            },
+        ]
+    );
+
+    let res = Tag::update_batch((1..=4).map(|id| tag::ActiveModel {
+        id: Unchanged(id),
+        usage: Set(id * 100),
+        ..Default::default()
+    }))
+    .exec(db)
+    .await?;
+    assert_eq!(res.rows_affected, 4);
+
+    assert_eq!(
+        Tag::find()
+            .order_by_asc(tag::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|tag| tag.usage)
+            .collect::<Vec<_>>(),
+        vec![100, 200, 300, 400]
+    );
+
+    Ok(())
+}
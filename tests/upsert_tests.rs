@@ -0,0 +1,117 @@
+pub mod common;
+
+pub use common::{features::*, setup::*, TestContext};
+use pretty_assertions::assert_eq;
+use sea_orm::{entity::prelude::*, entity::*, DatabaseConnection};
+
+#[sea_orm_macros::test]
+#[cfg(any(
+    feature = "sqlx-mysql",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-postgres"
+))]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("upsert_tests").await;
+    create_tables(&ctx.db).await?;
+    upsert_by_primary_key(&ctx.db).await?;
+    upsert_by_unique_key(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn upsert_by_primary_key(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let repository = repository::ActiveModel {
+        id: Set("unique-id-001".to_owned()),
+        owner: Set("GC".to_owned()),
+        name: Set("G.C.".to_owned()),
+        description: Set(None),
+    };
+
+    assert_eq!(
+        repository.upsert(db).await?,
+        (
+            repository::Model {
+                id: "unique-id-001".to_owned(),
+                owner: "GC".to_owned(),
+                name: "G.C.".to_owned(),
+                description: None,
+            },
+            true
+        )
+    );
+
+    let repository = repository::ActiveModel {
+        id: Set("unique-id-001".to_owned()),
+        owner: Set("SeaQL".to_owned()),
+        name: Set("G.C.".to_owned()),
+        description: Set(Some("description".to_owned())),
+    };
+
+    assert_eq!(
+        repository.upsert(db).await?,
+        (
+            repository::Model {
+                id: "unique-id-001".to_owned(),
+                owner: "SeaQL".to_owned(),
+                name: "G.C.".to_owned(),
+                description: Some("description".to_owned()),
+            },
+            false
+        )
+    );
+
+    assert_eq!(Repository::find().all(db).await?.len(), 1);
+
+    Ok(())
+}
+
+pub async fn upsert_by_unique_key(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let (rust, _) = tag::ActiveModel {
+        name: Set("rust".to_owned()),
+        usage: Set(1),
+        ..Default::default()
+    }
+    .upsert(db)
+    .await?;
+
+    tag::ActiveModel {
+        name: Set("orm".to_owned()),
+        usage: Set(1),
+        ..Default::default()
+    }
+    .upsert(db)
+    .await?;
+
+    assert_eq!(
+        tag::ActiveModel {
+            name: Set("rust".to_owned()),
+            usage: Set(2),
+            ..Default::default()
+        }
+        .upsert(db)
+        .await?,
+        (
+            tag::Model {
+                id: rust.id,
+                name: "rust".to_owned(),
+                usage: 2,
+            },
+            false
+        )
+    );
+
+    assert_eq!(
+        tag::ActiveModel {
+            usage: Set(3),
+            ..Default::default()
+        }
+        .upsert(db)
+        .await,
+        Err(DbErr::Custom(
+            "Neither the primary key nor any unique column is set for upsert".to_owned()
+        ))
+    );
+
+    Ok(())
+}